@@ -3,34 +3,113 @@ use getset::{
   MutGetters,
 };
 use sable_arena::TypedArena;
+use sable_common::file::FileId;
 
 use crate::{
-  expression::Expression,
-  objects::function::{
-    Function,
-    FunctionParam,
+  expression::{
+    Expression,
+    match_expression::MatchArm,
+    struct_literal_expression::FieldInitializer,
   },
+  located::Located,
+  objects::{
+    alias::TypeAlias,
+    enumeration::{
+      Enum,
+      EnumVariant,
+    },
+    function::{
+      Function,
+      FunctionParam,
+    },
+    global::Global,
+    import::Import,
+    structure::{
+      Struct,
+      StructField,
+    },
+  },
+  pattern::Pattern,
+  types::Type,
 };
 
+/// The arenas an `Ast` allocates its nodes in, one per kind of node. They
+/// have to outlive every `Ast` built on them.
+#[derive(Default)]
+pub struct Arenas<'ast, 'src> {
+  exprs: TypedArena<Expression<'ast, 'src>>,
+  params: TypedArena<FunctionParam<'src>>,
+  fields: TypedArena<StructField<'src>>,
+  variants: TypedArena<EnumVariant<'ast, 'src>>,
+  payloads: TypedArena<Located<'src, Type<'src>>>,
+  arms: TypedArena<MatchArm<'ast, 'src>>,
+  patterns: TypedArena<Pattern<'ast, 'src>>,
+  field_inits: TypedArena<FieldInitializer<'ast, 'src>>,
+}
+
 #[derive(Getters, MutGetters, Debug)]
 pub struct Ast<'ast, 'src> {
+  #[getset(get = "pub")]
+  file: FileId<'src>,
+  /// The text of the `//!` comments in the file, one per line.
+  #[getset(get_mut = "pub", get = "pub")]
+  docs: Vec<Located<'src, &'src str>>,
+  #[getset(get_mut = "pub", get = "pub")]
+  imports: Vec<Import<'src>>,
+  /// Indices of the `Ast`s this one imports, among those resolved together.
+  /// Filled in by name resolution.
+  #[getset(get_mut = "pub", get = "pub")]
+  dependencies: Vec<usize>,
   #[getset(get_mut = "pub", get = "pub")]
   funcs: Vec<Function<'ast, 'src>>,
+  #[getset(get_mut = "pub", get = "pub")]
+  structs: Vec<Struct<'ast, 'src>>,
+  #[getset(get_mut = "pub", get = "pub")]
+  globals: Vec<Global<'ast, 'src>>,
+  #[getset(get_mut = "pub", get = "pub")]
+  enums: Vec<Enum<'ast, 'src>>,
+  #[getset(get_mut = "pub", get = "pub")]
+  aliases: Vec<TypeAlias<'src>>,
   #[getset(get = "pub")]
   expr_arena: &'ast TypedArena<Expression<'ast, 'src>>,
   #[getset(get = "pub")]
   param_arena: &'ast TypedArena<FunctionParam<'src>>,
+  #[getset(get = "pub")]
+  field_arena: &'ast TypedArena<StructField<'src>>,
+  #[getset(get = "pub")]
+  variant_arena: &'ast TypedArena<EnumVariant<'ast, 'src>>,
+  /// Holds the payload types of enum variants.
+  #[getset(get = "pub")]
+  payload_arena: &'ast TypedArena<Located<'src, Type<'src>>>,
+  #[getset(get = "pub")]
+  arm_arena: &'ast TypedArena<MatchArm<'ast, 'src>>,
+  #[getset(get = "pub")]
+  pattern_arena: &'ast TypedArena<Pattern<'ast, 'src>>,
+  /// Holds the `name: value` fields of struct literals.
+  #[getset(get = "pub")]
+  field_init_arena: &'ast TypedArena<FieldInitializer<'ast, 'src>>,
 }
 
 impl<'ast, 'src> Ast<'ast, 'src> {
-  pub fn new(
-    expr_arena: &'ast TypedArena<Expression<'ast, 'src>>,
-    param_arena: &'ast TypedArena<FunctionParam<'src>>,
-  ) -> Self {
+  pub fn new(file: FileId<'src>, arenas: &'ast Arenas<'ast, 'src>) -> Self {
     Ast {
+      file,
+      docs: Vec::new(),
+      imports: Vec::new(),
+      dependencies: Vec::new(),
       funcs: Vec::new(),
-      expr_arena,
-      param_arena,
+      structs: Vec::new(),
+      globals: Vec::new(),
+      enums: Vec::new(),
+      aliases: Vec::new(),
+      expr_arena: &arenas.exprs,
+      param_arena: &arenas.params,
+      field_arena: &arenas.fields,
+      variant_arena: &arenas.variants,
+      payload_arena: &arenas.payloads,
+      arm_arena: &arenas.arms,
+      pattern_arena: &arenas.patterns,
+      field_init_arena: &arenas.field_inits,
     }
   }
 }
@@ -41,12 +120,18 @@ impl<'ast, 'src> serde::Serialize for Ast<'ast, 'src> {
   where
     S: serde::Serializer,
   {
-    use serde::ser::SerializeSeq;
+    use serde::ser::SerializeStruct;
 
-    let mut seq = serializer.serialize_seq(Some(self.funcs.len()))?;
-    for func in &self.funcs {
-      seq.serialize_element(func)?;
-    }
-    seq.end()
+    let mut state = serializer.serialize_struct("Ast", 9)?;
+    state.serialize_field("file", &self.file)?;
+    state.serialize_field("docs", &self.docs)?;
+    state.serialize_field("imports", &self.imports)?;
+    state.serialize_field("dependencies", &self.dependencies)?;
+    state.serialize_field("funcs", &self.funcs)?;
+    state.serialize_field("structs", &self.structs)?;
+    state.serialize_field("globals", &self.globals)?;
+    state.serialize_field("enums", &self.enums)?;
+    state.serialize_field("aliases", &self.aliases)?;
+    state.end()
   }
 }
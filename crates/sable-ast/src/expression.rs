@@ -1,19 +1,50 @@
+pub mod array_expression;
 pub mod assign_expression;
 pub mod binary_expression;
 pub mod block_expression;
+pub mod call_expression;
+pub mod cast_expression;
+pub mod field_expression;
 pub mod identifier_expression;
+pub mod if_expression;
+pub mod index_expression;
 pub mod literal_expression;
+pub mod loop_expression;
+pub mod match_expression;
+pub mod struct_literal_expression;
+pub mod unary_expression;
+pub mod while_expression;
 
+pub use array_expression::ArrayExpression;
 pub use assign_expression::AssignExpression;
-pub use binary_expression::BinaryExpression;
+pub use binary_expression::{
+  BinaryExpression,
+  BinaryOperator,
+};
 pub use block_expression::BlockExpression;
+pub use call_expression::CallExpression;
+pub use cast_expression::{
+  CastExpression,
+  CastKind,
+};
+pub use field_expression::FieldExpression;
 use getset::{
   Getters,
   MutGetters,
 };
 pub use identifier_expression::IdentifierExpression;
+pub use if_expression::IfExpression;
+pub use index_expression::IndexExpression;
 pub use literal_expression::LiteralExpression;
+pub use loop_expression::LoopExpression;
+pub use match_expression::MatchExpression;
+pub use struct_literal_expression::StructLiteralExpression;
+pub use unary_expression::{
+  UnaryExpression,
+  UnaryOperator,
+};
 use typed_builder::TypedBuilder;
+pub use while_expression::WhileExpression;
 
 use crate::NodeId;
 use sable_common::{
@@ -33,6 +64,23 @@ pub struct Expression<'ast, 'src> {
   id: Once<NodeId>,
 }
 
+impl<'ast, 'src> Expression<'ast, 'src> {
+  /// The value of an integer literal, possibly negated, without evaluating
+  /// anything else.
+  pub fn as_integer(&self) -> Option<i64> {
+    match &self.kind {
+      ExpressionKind::Literal(LiteralExpression::Integer(integer)) => {
+        let value = i64::try_from(*integer.value()).ok()?;
+        Some(if *integer.negative() { value.wrapping_neg() } else { value })
+      }
+      ExpressionKind::Unary(unary) if *unary.operator() == UnaryOperator::Negate => {
+        unary.operand().as_integer().map(i64::wrapping_neg)
+      }
+      _ => None,
+    }
+  }
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ExpressionKind<'ast, 'src> {
@@ -40,7 +88,18 @@ pub enum ExpressionKind<'ast, 'src> {
   Literal(LiteralExpression),
   Assign(AssignExpression<'ast, 'src>),
   Binary(BinaryExpression<'ast, 'src>),
-  Identifier(IdentifierExpression),
+  Unary(UnaryExpression<'ast, 'src>),
+  Call(CallExpression<'ast, 'src>),
+  If(IfExpression<'ast, 'src>),
+  While(WhileExpression<'ast, 'src>),
+  Loop(LoopExpression<'ast, 'src>),
+  Match(MatchExpression<'ast, 'src>),
+  StructLiteral(StructLiteralExpression<'ast, 'src>),
+  Field(FieldExpression<'ast, 'src>),
+  Array(ArrayExpression<'ast, 'src>),
+  Index(IndexExpression<'ast, 'src>),
+  Cast(CastExpression<'ast, 'src>),
+  Identifier(IdentifierExpression<'src>),
 }
 
 pub trait ExpressionVisitor<'ast, 'src> {
@@ -66,9 +125,64 @@ pub trait ExpressionVisitor<'ast, 'src> {
     binary: &BinaryExpression<'ast, 'src>,
     expr: &Expression<'ast, 'src>,
   ) -> Self::VisitReturn;
+  fn visit_unary(
+    &mut self,
+    unary: &UnaryExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_call(
+    &mut self,
+    call: &CallExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_if(
+    &mut self,
+    if_: &IfExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_while(
+    &mut self,
+    while_: &WhileExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_loop(
+    &mut self,
+    loop_: &LoopExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_match(
+    &mut self,
+    match_: &MatchExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_struct_literal(
+    &mut self,
+    struct_literal: &StructLiteralExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_field(
+    &mut self,
+    field: &FieldExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_array(
+    &mut self,
+    array: &ArrayExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_index(
+    &mut self,
+    index: &IndexExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_cast(
+    &mut self,
+    cast: &CastExpression<'ast, 'src>,
+    expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
   fn visit_identifier(
     &mut self,
-    identifier: &IdentifierExpression,
+    identifier: &IdentifierExpression<'src>,
     expr: &Expression<'ast, 'src>,
   ) -> Self::VisitReturn;
   fn visit_expr(&mut self, expr: &Expression<'ast, 'src>) -> Self::VisitReturn {
@@ -77,6 +191,19 @@ pub trait ExpressionVisitor<'ast, 'src> {
       ExpressionKind::Literal(literal) => self.visit_literal(literal, expr),
       ExpressionKind::Assign(assign) => self.visit_assign(assign, expr),
       ExpressionKind::Binary(binary) => self.visit_binary(binary, expr),
+      ExpressionKind::Unary(unary) => self.visit_unary(unary, expr),
+      ExpressionKind::Call(call) => self.visit_call(call, expr),
+      ExpressionKind::If(if_) => self.visit_if(if_, expr),
+      ExpressionKind::While(while_) => self.visit_while(while_, expr),
+      ExpressionKind::Loop(loop_) => self.visit_loop(loop_, expr),
+      ExpressionKind::Match(match_) => self.visit_match(match_, expr),
+      ExpressionKind::StructLiteral(struct_literal) => {
+        self.visit_struct_literal(struct_literal, expr)
+      }
+      ExpressionKind::Field(field) => self.visit_field(field, expr),
+      ExpressionKind::Array(array) => self.visit_array(array, expr),
+      ExpressionKind::Index(index) => self.visit_index(index, expr),
+      ExpressionKind::Cast(cast) => self.visit_cast(cast, expr),
       ExpressionKind::Identifier(identifier) => self.visit_identifier(identifier, expr),
     }
   }
@@ -105,9 +232,64 @@ pub trait ExpressionVisitorMut<'ast, 'src> {
     binary: &mut BinaryExpression<'ast, 'src>,
     expr: &mut Expression<'ast, 'src>,
   ) -> Self::VisitReturn;
+  fn visit_unary_mut(
+    &mut self,
+    unary: &mut UnaryExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_call_mut(
+    &mut self,
+    call: &mut CallExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_if_mut(
+    &mut self,
+    if_: &mut IfExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_while_mut(
+    &mut self,
+    while_: &mut WhileExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_loop_mut(
+    &mut self,
+    loop_: &mut LoopExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_match_mut(
+    &mut self,
+    match_: &mut MatchExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_struct_literal_mut(
+    &mut self,
+    struct_literal: &mut StructLiteralExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_field_mut(
+    &mut self,
+    field: &mut FieldExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_array_mut(
+    &mut self,
+    array: &mut ArrayExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_index_mut(
+    &mut self,
+    index: &mut IndexExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
+  fn visit_cast_mut(
+    &mut self,
+    cast: &mut CastExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn;
   fn visit_identifier_mut(
     &mut self,
-    identifier: &mut IdentifierExpression,
+    identifier: &mut IdentifierExpression<'src>,
     expr: &mut Expression<'ast, 'src>,
   ) -> Self::VisitReturn;
   fn visit_expr_mut(&mut self, expr: &mut Expression<'ast, 'src>) -> Self::VisitReturn {
@@ -123,6 +305,19 @@ pub trait ExpressionVisitorMut<'ast, 'src> {
         ExpressionKind::Literal(literal) => self.visit_literal_mut(literal, &mut *expr_ptr),
         ExpressionKind::Assign(assign) => self.visit_assign_mut(assign, &mut *expr_ptr),
         ExpressionKind::Binary(binary) => self.visit_binary_mut(binary, &mut *expr_ptr),
+        ExpressionKind::Unary(unary) => self.visit_unary_mut(unary, &mut *expr_ptr),
+        ExpressionKind::Call(call) => self.visit_call_mut(call, &mut *expr_ptr),
+        ExpressionKind::If(if_) => self.visit_if_mut(if_, &mut *expr_ptr),
+        ExpressionKind::While(while_) => self.visit_while_mut(while_, &mut *expr_ptr),
+        ExpressionKind::Loop(loop_) => self.visit_loop_mut(loop_, &mut *expr_ptr),
+        ExpressionKind::Match(match_) => self.visit_match_mut(match_, &mut *expr_ptr),
+        ExpressionKind::StructLiteral(struct_literal) => {
+          self.visit_struct_literal_mut(struct_literal, &mut *expr_ptr)
+        }
+        ExpressionKind::Field(field) => self.visit_field_mut(field, &mut *expr_ptr),
+        ExpressionKind::Array(array) => self.visit_array_mut(array, &mut *expr_ptr),
+        ExpressionKind::Index(index) => self.visit_index_mut(index, &mut *expr_ptr),
+        ExpressionKind::Cast(cast) => self.visit_cast_mut(cast, &mut *expr_ptr),
         ExpressionKind::Identifier(identifier) => {
           self.visit_identifier_mut(identifier, &mut *expr_ptr)
         }
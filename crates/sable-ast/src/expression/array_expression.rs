@@ -0,0 +1,73 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::expression::{
+  Expression,
+  ExpressionVisitor,
+  ExpressionVisitorMut,
+  VisitableExpr,
+  VisitableExprMut,
+};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ArrayExpression<'ast, 'src> {
+  /// `[1, 2, 3]`
+  List(ArrayListExpression<'ast, 'src>),
+  /// `[0; 16]`
+  Repeat(ArrayRepeatExpression<'ast, 'src>),
+}
+
+impl<'ast, 'src> ArrayExpression<'ast, 'src> {
+  /// The number of elements, if it is known without evaluating anything.
+  pub fn known_len(&self) -> Option<u64> {
+    match self {
+      ArrayExpression::List(list) => Some(list.elements.len() as u64),
+      ArrayExpression::Repeat(repeat) => repeat
+        .count
+        .as_integer()
+        .and_then(|count| u64::try_from(count).ok()),
+    }
+  }
+}
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ArrayListExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  elements: &'ast mut [Expression<'ast, 'src>],
+}
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ArrayRepeatExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  value: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  count: &'ast mut Expression<'ast, 'src>,
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for ArrayExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_array(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for ArrayExpression<'ast, 'src> {
+  fn accept_mut<V>(
+    &mut self,
+    expr: &mut Expression<'ast, 'src>,
+    visitor: &mut V,
+  ) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_array_mut(self, expr)
+  }
+}
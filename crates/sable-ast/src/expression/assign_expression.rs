@@ -4,23 +4,24 @@ use getset::{
 };
 use typed_builder::TypedBuilder;
 
-use crate::{
-  expression::{
-    Expression,
-    ExpressionVisitor,
-    ExpressionVisitorMut,
-    VisitableExpr,
-    VisitableExprMut,
-  },
-  located::Located,
+use crate::expression::{
+  BinaryOperator,
+  Expression,
+  ExpressionVisitor,
+  ExpressionVisitorMut,
+  VisitableExpr,
+  VisitableExprMut,
 };
-use sable_common::interner::Entry;
 
+/// `target = value`, or `target op= value` when `operator` is set.
 #[derive(Debug, MutGetters, TypedBuilder, Getters)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AssignExpression<'ast, 'src> {
   #[getset(get = "pub")]
-  identifier: Located<'src, Entry>,
+  #[builder(default)]
+  operator: Option<BinaryOperator>,
+  #[getset(get = "pub", get_mut = "pub")]
+  target: &'ast mut Expression<'ast, 'src>,
   #[getset(get = "pub", get_mut = "pub")]
   value: &'ast mut Expression<'ast, 'src>,
 }
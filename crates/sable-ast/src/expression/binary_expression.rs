@@ -33,7 +33,37 @@ macro_rules! binary_expr_factory {
         )*
       }
 
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+      #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+      pub enum BinaryOperator {
+        $(
+          $name,
+        )*
+      }
+
       impl<'ast, 'src> BinaryExpression<'ast, 'src> {
+        pub fn new(
+          operator: BinaryOperator,
+          left: &'ast mut Expression<'ast, 'src>,
+          right: &'ast mut Expression<'ast, 'src>,
+        ) -> Self {
+          match operator {
+            $(
+              BinaryOperator::$name => BinaryExpression::$name(
+                [<$name Expression>]::builder().left(left).right(right).build(),
+              ),
+            )*
+          }
+        }
+
+        pub fn operator(&self) -> BinaryOperator {
+          match self {
+            $(
+              BinaryExpression::$name(_) => BinaryOperator::$name,
+            )*
+          }
+        }
+
         pub fn lhs(&self) -> &Expression<'ast, 'src> {
           match self {
             $(
@@ -92,4 +122,23 @@ macro_rules! binary_expr_factory {
   };
 }
 
-binary_expr_factory!(Add, Subtract, Multiply, Divide,);
+binary_expr_factory!(
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+  Remainder,
+  Equal,
+  NotEqual,
+  Less,
+  LessEqual,
+  Greater,
+  GreaterEqual,
+  LogicalAnd,
+  LogicalOr,
+  BitAnd,
+  BitOr,
+  BitXor,
+  ShiftLeft,
+  ShiftRight,
+);
@@ -19,6 +19,9 @@ use typed_builder::TypedBuilder;
 pub struct BlockExpression<'ast, 'src> {
   #[getset(get = "pub", get_mut = "pub")]
   body: Vec<Statement<'ast, 'src>>,
+  #[getset(get = "pub", get_mut = "pub")]
+  #[builder(default)]
+  tail: Option<&'ast mut Expression<'ast, 'src>>,
 }
 
 impl<'ast, 'src> VisitableExpr<'ast, 'src> for BlockExpression<'ast, 'src> {
@@ -0,0 +1,42 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::expression::{
+  Expression,
+  ExpressionVisitor,
+  ExpressionVisitorMut,
+  VisitableExpr,
+  VisitableExprMut,
+};
+
+pub const MAX_INLINE_ARGS: usize = 6;
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CallExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  callee: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  args: &'ast mut [Expression<'ast, 'src>],
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for CallExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_call(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for CallExpression<'ast, 'src> {
+  fn accept_mut<V>(&mut self, expr: &mut Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_call_mut(self, expr)
+  }
+}
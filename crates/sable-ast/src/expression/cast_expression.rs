@@ -0,0 +1,72 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use sable_common::once::Once;
+use typed_builder::TypedBuilder;
+
+use crate::{
+  expression::{
+    Expression,
+    ExpressionVisitor,
+    ExpressionVisitorMut,
+    VisitableExpr,
+    VisitableExprMut,
+  },
+  located::Located,
+  types::Type,
+};
+
+/// What a cast does to its operand, as decided during semantic analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CastKind {
+  /// Source and target are the same type.
+  Identity,
+  /// To a wider integer or float, e.g. `i32` to `i64` or `f32` to `f64`.
+  Widen,
+  /// To a narrower integer or float, e.g. `i64` to `u8`.
+  Truncate,
+  /// Between integers of the same width but different signedness.
+  SignChange,
+  IntToFloat,
+  FloatToInt,
+  PointerToInt,
+  IntToPointer,
+  PointerToPointer,
+}
+
+/// `value as Type`
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CastExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  value: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  type_: Located<'src, Type<'src>>,
+  #[getset(get = "pub", get_mut = "pub")]
+  #[builder(default)]
+  kind: Once<CastKind>,
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for CastExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_cast(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for CastExpression<'ast, 'src> {
+  fn accept_mut<V>(
+    &mut self,
+    expr: &mut Expression<'ast, 'src>,
+    visitor: &mut V,
+  ) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_cast_mut(self, expr)
+  }
+}
@@ -0,0 +1,46 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  expression::{
+    Expression,
+    ExpressionVisitor,
+    ExpressionVisitorMut,
+    VisitableExpr,
+    VisitableExprMut,
+  },
+  located::Located,
+};
+use sable_common::interner::Entry;
+
+/// `object.field`. The object may be a struct value or a pointer to one, in
+/// which case the access dereferences it implicitly.
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  object: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub")]
+  field: Located<'src, Entry>,
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for FieldExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_field(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for FieldExpression<'ast, 'src> {
+  fn accept_mut<V>(&mut self, expr: &mut Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_field_mut(self, expr)
+  }
+}
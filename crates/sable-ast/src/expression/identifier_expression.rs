@@ -1,20 +1,21 @@
 use getset::Getters;
 use typed_builder::TypedBuilder;
 
-use sable_common::interner::Entry;
-
-use crate::expression::{
-  Expression, ExpressionVisitor, ExpressionVisitorMut, VisitableExpr, VisitableExprMut,
+use crate::{
+  expression::{
+    Expression, ExpressionVisitor, ExpressionVisitorMut, VisitableExpr, VisitableExprMut,
+  },
+  types::Path,
 };
 
 #[derive(Debug, TypedBuilder, Getters)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
-pub struct IdentifierExpression {
+pub struct IdentifierExpression<'src> {
   #[getset(get = "pub")]
-  pub name: Entry,
+  pub path: Path<'src>,
 }
 
-impl<'ast, 'src> VisitableExpr<'ast, 'src> for IdentifierExpression {
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for IdentifierExpression<'src> {
   fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
   where
     V: ExpressionVisitor<'ast, 'src>,
@@ -23,7 +24,7 @@ impl<'ast, 'src> VisitableExpr<'ast, 'src> for IdentifierExpression {
   }
 }
 
-impl<'ast, 'src> VisitableExprMut<'ast, 'src> for IdentifierExpression {
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for IdentifierExpression<'src> {
   fn accept_mut<V>(
     &mut self,
     expr: &mut Expression<'ast, 'src>,
@@ -0,0 +1,44 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::expression::{
+  BlockExpression,
+  Expression,
+  ExpressionVisitor,
+  ExpressionVisitorMut,
+  VisitableExpr,
+  VisitableExprMut,
+};
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IfExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  condition: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  then_block: BlockExpression<'ast, 'src>,
+  /// Either a `Block` for a plain `else` or another `If` for `else if`.
+  #[getset(get = "pub", get_mut = "pub")]
+  else_branch: Option<&'ast mut Expression<'ast, 'src>>,
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for IfExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_if(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for IfExpression<'ast, 'src> {
+  fn accept_mut<V>(&mut self, expr: &mut Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_if_mut(self, expr)
+  }
+}
@@ -0,0 +1,45 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::expression::{
+  Expression,
+  ExpressionVisitor,
+  ExpressionVisitorMut,
+  VisitableExpr,
+  VisitableExprMut,
+};
+
+/// `object[index]`
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IndexExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  object: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  index: &'ast mut Expression<'ast, 'src>,
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for IndexExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_index(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for IndexExpression<'ast, 'src> {
+  fn accept_mut<V>(
+    &mut self,
+    expr: &mut Expression<'ast, 'src>,
+    visitor: &mut V,
+  ) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_index_mut(self, expr)
+  }
+}
@@ -1,19 +1,29 @@
 use getset::Getters;
+use sable_common::interner::Entry;
 use typed_builder::TypedBuilder;
 
-use crate::expression::{
-  Expression,
-  ExpressionVisitor,
-  ExpressionVisitorMut,
-  VisitableExpr,
-  VisitableExprMut,
+use crate::{
+  expression::{
+    Expression,
+    ExpressionVisitor,
+    ExpressionVisitorMut,
+    VisitableExpr,
+    VisitableExprMut,
+  },
+  token::{
+    FloatSuffix,
+    IntegerSuffix,
+  },
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum LiteralExpression {
   Integer(IntegerExpression),
   Float(FloatExpression),
+  String(StringExpression),
+  Char(CharExpression),
+  Bool(BoolExpression),
 }
 
 impl<'ast, 'src> VisitableExpr<'ast, 'src> for LiteralExpression {
@@ -38,16 +48,47 @@ impl<'ast, 'src> VisitableExprMut<'ast, 'src> for LiteralExpression {
   }
 }
 
-#[derive(Debug, Getters, TypedBuilder)]
+#[derive(Debug, Clone, Getters, TypedBuilder)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct IntegerExpression {
+  /// The magnitude; negative only in patterns such as `-1 => ..`.
   #[getset(get = "pub")]
-  value: i64,
+  value: u128,
+  #[getset(get = "pub")]
+  #[builder(default)]
+  negative: bool,
+  #[getset(get = "pub")]
+  #[builder(default)]
+  suffix: Option<IntegerSuffix>,
 }
 
-#[derive(Debug, Getters, TypedBuilder)]
+#[derive(Debug, Clone, Getters, TypedBuilder)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FloatExpression {
   #[getset(get = "pub")]
   value: f64,
+  #[getset(get = "pub")]
+  #[builder(default)]
+  suffix: Option<FloatSuffix>,
+}
+
+#[derive(Debug, Clone, Getters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StringExpression {
+  #[getset(get = "pub")]
+  value: Entry,
+}
+
+#[derive(Debug, Clone, Getters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CharExpression {
+  #[getset(get = "pub")]
+  value: char,
+}
+
+#[derive(Debug, Clone, Getters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BoolExpression {
+  #[getset(get = "pub")]
+  value: bool,
 }
@@ -0,0 +1,39 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::expression::{
+  BlockExpression,
+  Expression,
+  ExpressionVisitor,
+  ExpressionVisitorMut,
+  VisitableExpr,
+  VisitableExprMut,
+};
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LoopExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  body: BlockExpression<'ast, 'src>,
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for LoopExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_loop(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for LoopExpression<'ast, 'src> {
+  fn accept_mut<V>(&mut self, expr: &mut Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_loop_mut(self, expr)
+  }
+}
@@ -0,0 +1,62 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use sable_common::location::Location;
+use typed_builder::TypedBuilder;
+
+use crate::{
+  expression::{
+    Expression,
+    ExpressionVisitor,
+    ExpressionVisitorMut,
+    VisitableExpr,
+    VisitableExprMut,
+  },
+  pattern::Pattern,
+};
+
+pub const MAX_INLINE_ARMS: usize = 8;
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MatchArm<'ast, 'src> {
+  #[getset(get = "pub")]
+  pattern: Pattern<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  body: Expression<'ast, 'src>,
+}
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MatchExpression<'ast, 'src> {
+  /// Location of the `match` keyword, where exhaustiveness errors point.
+  #[getset(get = "pub")]
+  keyword: Location<'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  scrutinee: &'ast mut Expression<'ast, 'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  arms: &'ast mut [MatchArm<'ast, 'src>],
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for MatchExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_match(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for MatchExpression<'ast, 'src> {
+  fn accept_mut<V>(
+    &mut self,
+    expr: &mut Expression<'ast, 'src>,
+    visitor: &mut V,
+  ) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_match_mut(self, expr)
+  }
+}
@@ -0,0 +1,54 @@
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  expression::{
+    Expression,
+    ExpressionVisitor,
+    ExpressionVisitorMut,
+    VisitableExpr,
+    VisitableExprMut,
+  },
+  located::Located,
+  types::Path,
+};
+use sable_common::interner::Entry;
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldInitializer<'ast, 'src> {
+  #[getset(get = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub", get_mut = "pub")]
+  value: Expression<'ast, 'src>,
+}
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StructLiteralExpression<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  path: Path<'src>,
+  #[getset(get = "pub", get_mut = "pub")]
+  fields: &'ast mut [FieldInitializer<'ast, 'src>],
+}
+
+impl<'ast, 'src> VisitableExpr<'ast, 'src> for StructLiteralExpression<'ast, 'src> {
+  fn accept<V>(&self, expr: &Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitor<'ast, 'src>,
+  {
+    visitor.visit_struct_literal(self, expr)
+  }
+}
+
+impl<'ast, 'src> VisitableExprMut<'ast, 'src> for StructLiteralExpression<'ast, 'src> {
+  fn accept_mut<V>(&mut self, expr: &mut Expression<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: ExpressionVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_struct_literal_mut(self, expr)
+  }
+}
@@ -1,9 +1,8 @@
-#![feature(allocator_api)]
-
 pub mod ast;
 pub mod expression;
 pub mod located;
 pub mod objects;
+pub mod pattern;
 pub mod statement;
 pub mod token;
 pub mod types;
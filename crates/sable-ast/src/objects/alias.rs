@@ -0,0 +1,26 @@
+use getset::{
+  Getters,
+  MutGetters,
+  Setters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  located::Located,
+  types::Type,
+};
+use sable_common::interner::Entry;
+
+/// `type Name = Type;`. The alias is interchangeable with the type it names.
+#[derive(Getters, MutGetters, Setters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TypeAlias<'src> {
+  #[getset(get = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub", get_mut = "pub")]
+  type_: Located<'src, Type<'src>>,
+  /// The `///` lines above `type`, describing the alias rather than its target.
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  docs: Vec<Located<'src, &'src str>>,
+}
@@ -0,0 +1,37 @@
+use getset::{
+  Getters,
+  Setters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  located::Located,
+  types::Type,
+};
+use sable_common::interner::Entry;
+
+pub const MAX_INLINE_VARIANTS: usize = 8;
+
+/// A single variant such as `Rect(f64, f64)`. Unit variants have an empty
+/// payload.
+#[derive(Getters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EnumVariant<'ast, 'src> {
+  #[getset(get = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub")]
+  payload: &'ast [Located<'src, Type<'src>>],
+}
+
+#[derive(Getters, Setters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Enum<'ast, 'src> {
+  #[getset(get = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub", set = "pub")]
+  variants: &'ast [EnumVariant<'ast, 'src>],
+  /// What the enum stands for, from its `///` lines; variants carry none.
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  docs: Vec<Located<'src, &'src str>>,
+}
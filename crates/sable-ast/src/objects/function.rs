@@ -41,15 +41,40 @@ impl<'src> From<Located<'src, TypeNamePair<'src>>> for FunctionParam<'src> {
   }
 }
 
-#[derive(Getters, MutGetters, TypedBuilder, Debug)]
+#[derive(Getters, MutGetters, Setters, TypedBuilder, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Function<'ast, 'src> {
   #[getset(get = "pub")]
   name: Located<'src, Entry>,
+  /// Type parameters of `func max<T>(...)`, empty for ordinary functions.
   #[getset(get = "pub")]
+  generics: Vec<Located<'src, Entry>>,
+  #[getset(get = "pub", set = "pub")]
   params: &'ast [FunctionParam<'src>],
-  #[getset(get = "pub")]
+  #[getset(get = "pub", get_mut = "pub")]
   return_type: Located<'src, Type<'src>>,
   #[getset(get = "pub", get_mut = "pub")]
   block: Option<BlockExpression<'ast, 'src>>,
+  /// The calling convention of an `extern "..."` declaration.
+  #[getset(get = "pub")]
+  abi: Option<Located<'src, Entry>>,
+  /// Whether the parameter list ends in `...`. Only allowed on `extern`
+  /// declarations.
+  #[getset(get = "pub")]
+  variadic: bool,
+  /// The `///` lines above the `func` or `extern`, without the markers.
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  docs: Vec<Located<'src, &'src str>>,
+}
+
+impl<'ast, 'src> Function<'ast, 'src> {
+  /// External functions are defined elsewhere and only imported by name.
+  pub fn is_import(&self) -> bool {
+    self.abi.is_some()
+  }
+
+  pub fn is_generic(&self) -> bool {
+    !self.generics.is_empty()
+  }
 }
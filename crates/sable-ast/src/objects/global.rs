@@ -0,0 +1,38 @@
+use getset::{
+  Getters,
+  MutGetters,
+  Setters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  expression::Expression,
+  located::Located,
+  types::Type,
+};
+use sable_common::interner::Entry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum GlobalKind {
+  Const,
+  Var,
+}
+
+/// A file-scope `const NAME: T = expr;` or `var NAME: T = expr;`.
+#[derive(Getters, MutGetters, Setters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Global<'ast, 'src> {
+  #[getset(get = "pub")]
+  kind: GlobalKind,
+  #[getset(get = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub", get_mut = "pub")]
+  type_: Located<'src, Type<'src>>,
+  #[getset(get = "pub", get_mut = "pub")]
+  initializer: Expression<'ast, 'src>,
+  /// The `///` lines above the `const` or `var`, without the markers.
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  docs: Vec<Located<'src, &'src str>>,
+}
@@ -0,0 +1,34 @@
+use getset::{
+  Getters,
+  Setters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::located::Located;
+use sable_common::interner::Entry;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ImportKind {
+  /// `import math;` names another file by its module name, the file name
+  /// without directory or extension.
+  Module,
+  /// `import "other.sable";` names another file by its path, relative to the
+  /// importing file.
+  File,
+}
+
+/// `import math;` or `import "other.sable";`. Makes the items of another file
+/// reachable as `math::add`.
+#[derive(Getters, Setters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Import<'src> {
+  #[getset(get = "pub")]
+  kind: ImportKind,
+  #[getset(get = "pub")]
+  target: Located<'src, Entry>,
+  /// Why the module is imported, from the `///` lines above `import`.
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  docs: Vec<Located<'src, &'src str>>,
+}
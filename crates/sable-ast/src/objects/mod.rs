@@ -1 +1,6 @@
+pub mod alias;
+pub mod enumeration;
 pub mod function;
+pub mod global;
+pub mod import;
+pub mod structure;
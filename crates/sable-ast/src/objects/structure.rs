@@ -0,0 +1,61 @@
+use getset::{
+  Getters,
+  Setters,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  located::Located,
+  types::{
+    Type,
+    TypeNamePair,
+  },
+};
+use sable_common::interner::Entry;
+
+pub const MAX_INLINE_FIELDS: usize = 8;
+
+#[derive(Getters, Setters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StructField<'src> {
+  #[getset(get = "pub", set = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub")]
+  type_: Located<'src, Type<'src>>,
+}
+
+impl<'src> From<Located<'src, TypeNamePair<'src>>> for StructField<'src> {
+  fn from(pair: Located<'src, TypeNamePair<'src>>) -> Self {
+    Self {
+      name: Located::builder()
+        .value(*pair.value().name())
+        .location(pair.location().clone())
+        .build(),
+      type_: Located::builder()
+        .value(pair.value().type_().clone())
+        .location(pair.location().clone())
+        .build(),
+    }
+  }
+}
+
+#[derive(Getters, Setters, TypedBuilder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Struct<'ast, 'src> {
+  #[getset(get = "pub")]
+  name: Located<'src, Entry>,
+  #[getset(get = "pub")]
+  generics: Vec<Located<'src, Entry>>,
+  #[getset(get = "pub", set = "pub")]
+  fields: &'ast [StructField<'src>],
+  /// What the struct is for, from its `///` lines; its fields carry none.
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  docs: Vec<Located<'src, &'src str>>,
+}
+
+impl<'ast, 'src> Struct<'ast, 'src> {
+  pub fn is_generic(&self) -> bool {
+    !self.generics.is_empty()
+  }
+}
@@ -0,0 +1,48 @@
+use getset::Getters;
+use sable_common::{
+  interner::Entry,
+  location::Location,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{
+  expression::LiteralExpression,
+  types::Path,
+};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PatternKind<'ast, 'src> {
+  /// `_`
+  Wildcard,
+  /// A lone identifier, which binds the matched value to a new name.
+  Binding(Entry),
+  Literal(LiteralExpression),
+  /// `Shape::Circle(r)` or `Shape::Empty`.
+  Variant(VariantPattern<'ast, 'src>),
+}
+
+#[derive(Debug, Getters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VariantPattern<'ast, 'src> {
+  #[getset(get = "pub")]
+  path: Path<'src>,
+  #[getset(get = "pub")]
+  fields: &'ast [Pattern<'ast, 'src>],
+}
+
+#[derive(Debug, Getters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Pattern<'ast, 'src> {
+  #[getset(get = "pub")]
+  kind: PatternKind<'ast, 'src>,
+  #[getset(get = "pub")]
+  location: Location<'src>,
+}
+
+impl<'ast, 'src> Pattern<'ast, 'src> {
+  /// Whether the pattern matches every value of its type.
+  pub fn is_irrefutable(&self) -> bool {
+    matches!(self.kind, PatternKind::Wildcard | PatternKind::Binding(_))
+  }
+}
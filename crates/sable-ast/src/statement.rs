@@ -3,6 +3,8 @@ use crate::{
   NodeId,
 };
 
+pub mod break_statement;
+pub mod return_statement;
 pub mod variable_statement;
 
 use getset::{
@@ -14,6 +16,8 @@ use sable_common::{
   once::Once,
 };
 use typed_builder::TypedBuilder;
+pub use break_statement::BreakStatement;
+pub use return_statement::ReturnStatement;
 pub use variable_statement::VariableStatement;
 
 #[derive(Debug, TypedBuilder, Getters, MutGetters)]
@@ -33,6 +37,9 @@ pub struct Statement<'ast, 'src> {
 pub enum StatementKind<'ast, 'src> {
   Expression(Expression<'ast, 'src>),
   Variable(VariableStatement<'ast, 'src>),
+  Return(ReturnStatement<'ast, 'src>),
+  Break(BreakStatement<'ast, 'src>),
+  Continue,
 }
 
 pub trait StatementVisitor<'ast, 'src> {
@@ -50,10 +57,27 @@ pub trait StatementVisitor<'ast, 'src> {
     statement: &Statement<'ast, 'src>,
   ) -> Self::VisitReturn;
 
+  fn visit_return(
+    &mut self,
+    return_: &ReturnStatement<'ast, 'src>,
+    statement: &Statement<'ast, 'src>,
+  ) -> Self::VisitReturn;
+
+  fn visit_break(
+    &mut self,
+    break_: &BreakStatement<'ast, 'src>,
+    statement: &Statement<'ast, 'src>,
+  ) -> Self::VisitReturn;
+
+  fn visit_continue(&mut self, statement: &Statement<'ast, 'src>) -> Self::VisitReturn;
+
   fn visit_stmt(&mut self, statement: &Statement<'ast, 'src>) -> Self::VisitReturn {
     match &statement.kind {
       StatementKind::Expression(expr) => self.visit_expression(expr, statement),
       StatementKind::Variable(variable) => self.visit_variable(variable, statement),
+      StatementKind::Return(return_) => self.visit_return(return_, statement),
+      StatementKind::Break(break_) => self.visit_break(break_, statement),
+      StatementKind::Continue => self.visit_continue(statement),
     }
   }
 }
@@ -73,6 +97,20 @@ pub trait StatementVisitorMut<'ast, 'src> {
     statement: &mut Statement<'ast, 'src>,
   ) -> Self::VisitReturn;
 
+  fn visit_return_mut(
+    &mut self,
+    return_: &mut ReturnStatement<'ast, 'src>,
+    statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn;
+
+  fn visit_break_mut(
+    &mut self,
+    break_: &mut BreakStatement<'ast, 'src>,
+    statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn;
+
+  fn visit_continue_mut(&mut self, statement: &mut Statement<'ast, 'src>) -> Self::VisitReturn;
+
   fn visit_stmt_mut(&mut self, statement: &mut Statement<'ast, 'src>) -> Self::VisitReturn {
     // SAFETY: see comment in `ExpressionVisitorMut::visit_expr_mut`. We need to
     // borrow both the statement and its inner kind mutably at the same time, so
@@ -82,6 +120,9 @@ pub trait StatementVisitorMut<'ast, 'src> {
       match &mut (*stmt_ptr).kind {
         StatementKind::Expression(expr) => self.visit_expression_mut(expr, &mut *stmt_ptr),
         StatementKind::Variable(variable) => self.visit_variable_mut(variable, &mut *stmt_ptr),
+        StatementKind::Return(return_) => self.visit_return_mut(return_, &mut *stmt_ptr),
+        StatementKind::Break(break_) => self.visit_break_mut(break_, &mut *stmt_ptr),
+        StatementKind::Continue => self.visit_continue_mut(&mut *stmt_ptr),
       }
     }
   }
@@ -0,0 +1,44 @@
+use crate::{
+  expression::Expression,
+  statement::{
+    Statement,
+    StatementVisitor,
+    StatementVisitorMut,
+    VisitableStmt,
+    VisitableStmtMut,
+  },
+};
+use getset::{
+  Getters,
+  MutGetters,
+};
+use typed_builder::TypedBuilder;
+
+#[derive(Debug, Getters, MutGetters, TypedBuilder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BreakStatement<'ast, 'src> {
+  #[getset(get = "pub", get_mut = "pub")]
+  value: Option<Expression<'ast, 'src>>,
+}
+
+impl<'ast, 'src> VisitableStmt<'ast, 'src> for BreakStatement<'ast, 'src> {
+  fn accept<V>(&self, statement: &Statement<'ast, 'src>, visitor: &mut V) -> V::VisitReturn
+  where
+    V: StatementVisitor<'ast, 'src>,
+  {
+    visitor.visit_break(self, statement)
+  }
+}
+
+impl<'ast, 'src> VisitableStmtMut<'ast, 'src> for BreakStatement<'ast, 'src> {
+  fn accept_mut<V>(
+    &mut self,
+    statement: &mut Statement<'ast, 'src>,
+    visitor: &mut V,
+  ) -> V::VisitReturn
+  where
+    V: StatementVisitorMut<'ast, 'src>,
+  {
+    visitor.visit_break_mut(self, statement)
+  }
+}
@@ -20,7 +20,7 @@ pub struct VariableStatement<'ast, 'src> {
   name: Located<'src, Entry>,
   #[getset(get = "pub", get_mut = "pub")]
   initializer: Expression<'ast, 'src>,
-  #[getset(get = "pub")]
+  #[getset(get = "pub", get_mut = "pub")]
   type_: Located<'src, Type<'src>>,
 }
 
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use getset::Getters;
 use sable_common::location::Location;
 #[cfg(feature = "serde")]
@@ -7,9 +9,161 @@ use serde::Serialize;
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum TokenError {
   UnknownCharacter,
-  InvalidInteger,
-  InvalidFloat,
+  InvalidInteger(IntegerError),
+  InvalidFloat(FloatError),
   UnterminatedComment,
+  UnterminatedString,
+  UnterminatedChar,
+  InvalidChar,
+  InvalidEscape(Range<usize>),
+}
+
+/// What is wrong with an integer literal. Ranges are source offsets.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum IntegerError {
+  /// `0x`, `0o` or `0b` without any digits after it.
+  MissingDigits,
+  /// A digit the radix does not allow, like the `2` in `0b102`.
+  InvalidDigit { radix: u32, range: Range<usize> },
+  UnknownSuffix(Range<usize>),
+  /// The value does not fit in 128 bits.
+  TooLarge,
+  /// The value does not fit in the type named by the suffix.
+  OutOfRange(IntegerSuffix),
+}
+
+/// What is wrong with a float literal. Ranges are source offsets.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum FloatError {
+  /// A hexadecimal float without digits before its exponent, like `0xp3`.
+  MissingDigits,
+  /// `e` or `p` without digits after it, like `1e+`.
+  MissingExponent,
+  /// A hexadecimal float without its `p` exponent, like `0x1.8`.
+  MissingBinaryExponent,
+  UnknownSuffix(Range<usize>),
+}
+
+/// Why the value of a float literal differs from what it spells out.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum FloatWarningKind {
+  /// It has more significant digits than the type holds, like
+  /// `16777217f32`.
+  Inexact,
+  /// It is too large for the type and becomes infinity.
+  Overflow,
+  /// It is too small for the type and becomes zero.
+  Underflow,
+}
+
+/// The type suffix of a float literal, as in `1.5f32`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum FloatSuffix {
+  F32,
+  F64,
+}
+
+impl FloatSuffix {
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "f32" => Some(FloatSuffix::F32),
+      "f64" => Some(FloatSuffix::F64),
+      _ => None,
+    }
+  }
+
+  /// The name of the suffix, which is also the name of its type.
+  pub fn name(self) -> &'static str {
+    match self {
+      FloatSuffix::F32 => "f32",
+      FloatSuffix::F64 => "f64",
+    }
+  }
+}
+
+/// The type suffix of an integer literal, as in `255u8`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum IntegerSuffix {
+  I8,
+  I16,
+  I32,
+  I64,
+  Isize,
+  U8,
+  U16,
+  U32,
+  U64,
+  Usize,
+}
+
+impl IntegerSuffix {
+  pub const ALL: [IntegerSuffix; 10] = [
+    IntegerSuffix::I8,
+    IntegerSuffix::I16,
+    IntegerSuffix::I32,
+    IntegerSuffix::I64,
+    IntegerSuffix::Isize,
+    IntegerSuffix::U8,
+    IntegerSuffix::U16,
+    IntegerSuffix::U32,
+    IntegerSuffix::U64,
+    IntegerSuffix::Usize,
+  ];
+
+  pub fn from_name(name: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|suffix| suffix.name() == name)
+  }
+
+  /// The name of the suffix, which is also the name of its type.
+  pub fn name(self) -> &'static str {
+    match self {
+      IntegerSuffix::I8 => "i8",
+      IntegerSuffix::I16 => "i16",
+      IntegerSuffix::I32 => "i32",
+      IntegerSuffix::I64 => "i64",
+      IntegerSuffix::Isize => "isize",
+      IntegerSuffix::U8 => "u8",
+      IntegerSuffix::U16 => "u16",
+      IntegerSuffix::U32 => "u32",
+      IntegerSuffix::U64 => "u64",
+      IntegerSuffix::Usize => "usize",
+    }
+  }
+
+  pub fn is_signed(self) -> bool {
+    matches!(
+      self,
+      IntegerSuffix::I8
+        | IntegerSuffix::I16
+        | IntegerSuffix::I32
+        | IntegerSuffix::I64
+        | IntegerSuffix::Isize
+    )
+  }
+
+  pub fn bits(self) -> u32 {
+    match self {
+      IntegerSuffix::I8 | IntegerSuffix::U8 => 8,
+      IntegerSuffix::I16 | IntegerSuffix::U16 => 16,
+      IntegerSuffix::I32 | IntegerSuffix::U32 => 32,
+      IntegerSuffix::I64 | IntegerSuffix::Isize | IntegerSuffix::U64 | IntegerSuffix::Usize => 64,
+    }
+  }
+
+  /// The largest literal the type can hold. Signed types accept their
+  /// minimum's magnitude, such as `128i8`, because the literal may be negated.
+  pub fn max(self) -> u128 {
+    if self.is_signed() {
+      1 << (self.bits() - 1)
+    } else {
+      (1 << self.bits()) - 1
+    }
+  }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -20,38 +174,111 @@ pub enum TokenKind {
   Eof,
   Error,
 
+  // Trivia
+  /// Spaces and tabs. Only produced by a lossless lexer.
+  Whitespace,
+  /// `\n` or `\r\n`. Only produced by a lossless lexer.
+  Newline,
+  /// `// text`. Only produced by a lossless lexer.
+  LineComment,
+  /// `/* text */`, including any comments nested in it. Only produced by a
+  /// lossless lexer.
+  BlockComment,
+  /// `/// text`, documenting the item that follows.
+  DocComment,
+  /// `//! text`, documenting the file it is in.
+  InnerDocComment,
+
   // Values
   Identifier,
   Integer,
   Float,
+  String,
+  Char,
 
   // Brackets
   Paren(bool),
   Brace(bool),
+  Bracket(bool),
 
   // Symbols
   Comma,
   Semicolon,
   Colon,
+  ColonColon,
+  Dot,
+  Ellipsis,
 
   // Operators
   Plus,
   Minus,
   Star,
   Slash,
+  Percent,
+  Bang,
   Assign,
+  FatArrow,
+  Equal,
+  NotEqual,
+  Less,
+  LessEqual,
+  Greater,
+  GreaterEqual,
+  LogicalAnd,
+  LogicalOr,
+  Ampersand,
+  Pipe,
+  Caret,
+  ShiftLeft,
+  ShiftRight,
+
+  // Compound assignment
+  PlusAssign,
+  MinusAssign,
+  StarAssign,
+  SlashAssign,
+  PercentAssign,
+  AmpersandAssign,
+  PipeAssign,
+  CaretAssign,
+  ShiftLeftAssign,
+  ShiftRightAssign,
 
   // Keywords
   Func,
   Var,
+  Return,
+  If,
+  Else,
+  While,
+  Loop,
+  Break,
+  Continue,
+  Struct,
+  Const,
+  Extern,
+  Enum,
+  Match,
+  As,
+  True,
+  False,
+  Type,
+  Import,
+  Underscore,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum TokenData {
   Error(TokenError),
-  Integer(i64),
-  Float(f64),
+  /// The value of an integer literal and its type suffix. The value is not
+  /// yet checked against the type the literal ends up with.
+  Integer(u128, Option<IntegerSuffix>),
+  /// The value of a float literal, already rounded to `f32` for that suffix,
+  /// and what was lost doing so.
+  Float(f64, Option<FloatSuffix>, Option<FloatWarningKind>),
+  String(String),
+  Char(char),
 }
 
 #[derive(Getters, Clone, Debug)]
@@ -81,4 +308,16 @@ impl<'src> Token<'src> {
       location,
     }
   }
+
+  /// The text of a doc comment, without its `///` or `//!` and the space
+  /// that usually follows.
+  pub fn doc_text(&self) -> Option<&'src str> {
+    match self.kind {
+      TokenKind::DocComment | TokenKind::InnerDocComment => {
+        let text = &self.lexeme[3..];
+        Some(text.strip_prefix(' ').unwrap_or(text).trim_end_matches('\r'))
+      }
+      _ => None,
+    }
+  }
 }
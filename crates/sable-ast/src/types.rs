@@ -1,15 +1,45 @@
-use getset::Getters;
+use getset::{
+  Getters,
+  Setters,
+};
 use sable_common::location::Location;
 use typed_builder::TypedBuilder;
 
 use crate::located::Located;
 use sable_common::interner::Entry;
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, TypedBuilder, Getters, Hash)]
+/// A `::`-separated name such as `math::Vec2`, optionally followed by type
+/// arguments as in `Pair<i32>`. Always has at least one segment.
+#[derive(Clone, Debug, PartialEq, Eq, Default, TypedBuilder, Getters, Setters, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Path<'src> {
   #[getset(get = "pub")]
   segments: Vec<Located<'src, Entry>>,
+  #[getset(get = "pub", set = "pub")]
+  #[builder(default)]
+  generics: Vec<Type<'src>>,
+}
+
+impl<'src> Path<'src> {
+  /// The final segment, which names the item itself.
+  pub fn name(&self) -> Entry {
+    *self.segments.last().expect("path without segments").value()
+  }
+
+  /// The segments without their locations.
+  pub fn names(&self) -> Vec<Entry> {
+    self.segments.iter().map(|segment| *segment.value()).collect()
+  }
+
+  pub fn is_single(&self) -> bool {
+    self.segments.len() == 1
+  }
+
+  pub fn location(&self) -> Location<'src> {
+    let first = self.segments.first().expect("path without segments");
+    let last = self.segments.last().expect("path without segments");
+    first.location().merge(last.location()).unwrap()
+  }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
@@ -19,6 +49,12 @@ pub enum Type<'src> {
   Infer,
   Path(Path<'src>),
   Pointer(Box<Type<'src>>),
+  /// `[T; N]`
+  Array(Box<Type<'src>>, u64),
+  /// `[T]`
+  Slice(Box<Type<'src>>),
+  /// `func(A, B): R`, the type of a function used as a value.
+  Function(Vec<Type<'src>>, Box<Type<'src>>),
 }
 
 #[derive(TypedBuilder, Getters)]
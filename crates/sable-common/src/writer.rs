@@ -1,5 +1,11 @@
 use crate::file::Span;
-use ariadne::Report;
+use ariadne::{
+  Config,
+  IndexType,
+  Report,
+  ReportBuilder,
+  ReportKind,
+};
 use std::io;
 
 use crate::cache::ErrorCache;
@@ -13,6 +19,16 @@ pub trait Reportable<'src> {
   fn report(&self) -> Report<'_, Span<'src>>;
 }
 
+/// Starts a report at `span`. `Location` ranges are byte offsets, which the
+/// report has to be told about to place labels right on lines with non-ASCII
+/// text.
+pub fn build_report<'a, 'src>(
+  kind: ReportKind<'a>,
+  span: Span<'src>,
+) -> ReportBuilder<'a, Span<'src>> {
+  Report::build(kind, span).with_config(Config::default().with_index_type(IndexType::Byte))
+}
+
 pub struct ReportWriter<'w, 'src, O> {
   cache: &'w mut ErrorCache<'src>,
   out: &'w mut O,
@@ -0,0 +1,119 @@
+use indexmap::IndexMap;
+use sable_ast::types::Type;
+use sable_common::interner::Entry;
+
+use crate::import;
+
+/// The types named by `type Name = Type;` items, by module and alias name.
+pub type Aliases<'src> = IndexMap<(Entry, Entry), Type<'src>>;
+
+/// The module and name of the alias `ty` refers to when written in `module`.
+fn alias_key<'src>(
+  ty: &Type<'src>,
+  module: Entry,
+  aliases: &Aliases<'src>,
+) -> Option<(Entry, Entry)> {
+  match ty {
+    Type::Path(path) if path.generics().is_empty() => {
+      import::lookup(aliases, module, &path.names()).map(|(key, _)| *key)
+    }
+    _ => None,
+  }
+}
+
+/// Replaces every alias in `ty`, written in `module`, however deeply nested,
+/// by the type it names. The aliases must be free of cycles.
+pub fn expand<'src>(ty: &Type<'src>, module: Entry, aliases: &Aliases<'src>) -> Type<'src> {
+  if let Some(key) = alias_key(ty, module, aliases) {
+    return expand(&aliases[&key], key.0, aliases);
+  }
+
+  match ty {
+    Type::Infer => Type::Infer,
+    Type::Pointer(inner) => Type::Pointer(Box::new(expand(inner, module, aliases))),
+    Type::Array(inner, length) => Type::Array(Box::new(expand(inner, module, aliases)), *length),
+    Type::Slice(inner) => Type::Slice(Box::new(expand(inner, module, aliases))),
+    Type::Function(params, return_type) => Type::Function(
+      params
+        .iter()
+        .map(|param| expand(param, module, aliases))
+        .collect(),
+      Box::new(expand(return_type, module, aliases)),
+    ),
+    Type::Path(path) => {
+      let mut path = path.clone();
+      let generics = path
+        .generics()
+        .iter()
+        .map(|arg| expand(arg, module, aliases))
+        .collect();
+      path.set_generics(generics);
+      Type::Path(path)
+    }
+  }
+}
+
+fn referenced<'src>(
+  ty: &Type<'src>,
+  module: Entry,
+  aliases: &Aliases<'src>,
+  out: &mut Vec<(Entry, Entry)>,
+) {
+  if let Some(key) = alias_key(ty, module, aliases) {
+    out.push(key);
+    return;
+  }
+
+  match ty {
+    Type::Infer => {}
+    Type::Pointer(inner) | Type::Array(inner, _) | Type::Slice(inner) => {
+      referenced(inner, module, aliases, out)
+    }
+    Type::Path(path) => {
+      for arg in path.generics() {
+        referenced(arg, module, aliases, out);
+      }
+    }
+    Type::Function(params, return_type) => {
+      for param in params {
+        referenced(param, module, aliases, out);
+      }
+      referenced(return_type, module, aliases, out);
+    }
+  }
+}
+
+fn walk<'src>(aliases: &Aliases<'src>, chain: &mut Vec<(Entry, Entry)>) -> bool {
+  let mut next = Vec::new();
+  let last = *chain.last().unwrap();
+  referenced(&aliases[&last], last.0, aliases, &mut next);
+
+  for name in next {
+    if name == chain[0] {
+      chain.push(name);
+      return true;
+    }
+    // Cycles that do not pass through the start are found from their own
+    // members.
+    if chain.contains(&name) {
+      continue;
+    }
+
+    chain.push(name);
+    if walk(aliases, chain) {
+      return true;
+    }
+    chain.pop();
+  }
+  false
+}
+
+/// Finds a chain of aliases leading from `start` back to itself, such as
+/// `[A, B, A]` for `type A = B*; type B = A;`.
+pub fn find_cycle<'src>(
+  start: (Entry, Entry),
+  aliases: &Aliases<'src>,
+) -> Option<Vec<(Entry, Entry)>> {
+  let mut chain = vec![start];
+  walk(aliases, &mut chain).then_some(chain)
+}
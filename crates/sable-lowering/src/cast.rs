@@ -0,0 +1,198 @@
+use sable_ast::{
+  expression::CastKind,
+  types::Type,
+};
+use sable_common::interner::StrInterner;
+
+/// The shapes of type that `as` can convert between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scalar {
+  Int { signed: bool, bits: u8 },
+  Float { bits: u8 },
+  Char,
+  Bool,
+  Pointer,
+}
+
+fn scalar<'src>(ty: &Type<'src>, intern: &StrInterner<'src>) -> Option<Scalar> {
+  let path = match ty {
+    // A function value is the address of its code.
+    Type::Pointer(_) | Type::Function(..) => return Some(Scalar::Pointer),
+    Type::Path(path) if path.is_single() && path.generics().is_empty() => path,
+    _ => return None,
+  };
+
+  let scalar = match intern.resolve(path.name())? {
+    "i8" => Scalar::Int { signed: true, bits: 8 },
+    "i16" => Scalar::Int { signed: true, bits: 16 },
+    "i32" => Scalar::Int { signed: true, bits: 32 },
+    "i64" | "isize" => Scalar::Int { signed: true, bits: 64 },
+    "u8" => Scalar::Int { signed: false, bits: 8 },
+    "u16" => Scalar::Int { signed: false, bits: 16 },
+    "u32" => Scalar::Int { signed: false, bits: 32 },
+    "u64" | "usize" => Scalar::Int { signed: false, bits: 64 },
+    "f32" => Scalar::Float { bits: 32 },
+    "f64" => Scalar::Float { bits: 64 },
+    "char" => Scalar::Char,
+    "bool" => Scalar::Bool,
+    _ => return None,
+  };
+  Some(scalar)
+}
+
+fn resize(from: u8, to: u8) -> CastKind {
+  match from.cmp(&to) {
+    std::cmp::Ordering::Less => CastKind::Widen,
+    std::cmp::Ordering::Equal => CastKind::Identity,
+    std::cmp::Ordering::Greater => CastKind::Truncate,
+  }
+}
+
+/// Decides what `from as to` does, or `None` if the cast is not allowed.
+/// Both types must already be resolved to the types they stand for.
+pub fn classify<'src>(
+  from: &Type<'src>,
+  to: &Type<'src>,
+  intern: &StrInterner<'src>,
+) -> Option<CastKind> {
+  use Scalar::*;
+
+  let kind = match (scalar(from, intern)?, scalar(to, intern)?) {
+    (
+      Int {
+        signed: from_signed,
+        bits: from_bits,
+      },
+      Int {
+        signed: to_signed,
+        bits: to_bits,
+      },
+    ) => match resize(from_bits, to_bits) {
+      CastKind::Identity if from_signed != to_signed => CastKind::SignChange,
+      kind => kind,
+    },
+    (Float { bits: from_bits }, Float { bits: to_bits }) => resize(from_bits, to_bits),
+    (Int { .. }, Float { .. }) => CastKind::IntToFloat,
+    (Float { .. }, Int { .. }) => CastKind::FloatToInt,
+    (Pointer, Int { .. }) => CastKind::PointerToInt,
+    (Int { .. }, Pointer) => CastKind::IntToPointer,
+    (Pointer, Pointer) => CastKind::PointerToPointer,
+    // A `char` is a 32-bit code point. Only bytes convert back, since every
+    // `u8` is a valid code point.
+    (Char, Char) => CastKind::Identity,
+    (Char, Int { bits, .. }) => resize(32, bits),
+    (Int { signed: false, bits: 8 }, Char) => CastKind::Widen,
+    // `false` and `true` become 0 and 1; nothing converts back.
+    (Bool, Bool) => CastKind::Identity,
+    (Bool, Int { .. }) => CastKind::Widen,
+    _ => return None,
+  };
+  Some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+  use heaped::arena::DroplessArena;
+  use sable_ast::{
+    expression::CastKind::{
+      self,
+      *,
+    },
+    located::Located,
+    types::{
+      Path,
+      Type,
+    },
+  };
+  use sable_common::{
+    interner::StrInterner,
+    location::Location,
+  };
+
+  use super::classify;
+
+  /// Parses the little type syntax the tests need: a name followed by `*`s.
+  fn ty<'src>(text: &str, intern: &StrInterner<'src>) -> Type<'src> {
+    if let Some(inner) = text.strip_suffix('*') {
+      return Type::Pointer(Box::new(ty(inner, intern)));
+    }
+    let segment = Located::builder()
+      .value(intern.intern(text))
+      .location(Location::new(0..text.len(), "main.sable"))
+      .build();
+    Type::Path(Path::builder().segments(vec![segment]).build())
+  }
+
+  fn check(cases: &[(&str, &str, Option<CastKind>)]) {
+    let strings = DroplessArena::new(1024);
+    let intern = StrInterner::new(&strings);
+    for (from, to, expected) in cases {
+      let kind = classify(&ty(from, &intern), &ty(to, &intern), &intern);
+      assert_eq!(kind, *expected, "{} as {}", from, to);
+    }
+  }
+
+  #[test]
+  fn test_integers() {
+    check(&[
+      ("i8", "i32", Some(Widen)),
+      ("u16", "i64", Some(Widen)),
+      ("i64", "i16", Some(Truncate)),
+      ("i32", "u32", Some(SignChange)),
+      ("isize", "i64", Some(Identity)),
+      ("u64", "usize", Some(Identity)),
+    ]);
+  }
+
+  #[test]
+  fn test_floats() {
+    check(&[
+      ("f32", "f64", Some(Widen)),
+      ("f64", "f32", Some(Truncate)),
+      ("i32", "f64", Some(IntToFloat)),
+      ("f64", "u8", Some(FloatToInt)),
+    ]);
+  }
+
+  #[test]
+  fn test_pointers() {
+    check(&[
+      ("i8*", "u64", Some(PointerToInt)),
+      ("usize", "i8*", Some(IntToPointer)),
+      ("i8*", "i32**", Some(PointerToPointer)),
+      ("f64", "i8*", None),
+      ("i8*", "f32", None),
+    ]);
+
+    let strings = DroplessArena::new(1024);
+    let intern = StrInterner::new(&strings);
+    let func = Type::Function(vec![ty("i32", &intern)], Box::new(ty("i32", &intern)));
+    assert_eq!(classify(&func, &ty("usize", &intern), &intern), Some(PointerToInt));
+  }
+
+  #[test]
+  fn test_chars_and_bools() {
+    check(&[
+      ("char", "u32", Some(Identity)),
+      ("char", "u8", Some(Truncate)),
+      ("char", "i64", Some(Widen)),
+      ("u8", "char", Some(Widen)),
+      ("u32", "char", None),
+      ("i8", "char", None),
+      ("bool", "i32", Some(Widen)),
+      ("bool", "bool", Some(Identity)),
+      ("i32", "bool", None),
+      ("bool", "f64", None),
+    ]);
+  }
+
+  #[test]
+  fn test_non_scalars() {
+    check(&[("Point", "i32", None), ("i32", "Point", None)]);
+
+    let strings = DroplessArena::new(1024);
+    let intern = StrInterner::new(&strings);
+    let array = Type::Array(Box::new(ty("i32", &intern)), 4);
+    assert_eq!(classify(&array, &ty("i32*", &intern), &intern), None);
+  }
+}
@@ -0,0 +1,143 @@
+use std::path::{
+  Component,
+  Path,
+  PathBuf,
+};
+
+use indexmap::IndexMap;
+use sable_common::interner::Entry;
+
+/// The name other files import `file` by: its file name without directory or
+/// extension, so `src/math.sable` is the module `math`.
+pub fn module_name(file: &str) -> &str {
+  Path::new(file)
+    .file_stem()
+    .and_then(|stem| stem.to_str())
+    .unwrap_or(file)
+}
+
+/// Removes `.` and resolves `..` without touching the file system, so that
+/// `./lib/../math.sable` and `math.sable` compare equal.
+pub fn normalize(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir if normalized.file_name().is_some() => {
+        normalized.pop();
+      }
+      _ => normalized.push(component),
+    }
+  }
+  normalized
+}
+
+/// The path `import "target";` in `importer` refers to.
+pub fn import_path(importer: &str, target: &str) -> PathBuf {
+  let directory = Path::new(importer).parent().unwrap_or(Path::new(""));
+  normalize(&directory.join(target))
+}
+
+/// Finds the item a path with `segments` names when written in `module`,
+/// among `items` keyed by module and item name. `other::Name` names an item of
+/// `other`. A lone name prefers an item of `module` and otherwise finds one in
+/// any module, as functions are found.
+pub fn lookup<'i, T>(
+  items: &'i IndexMap<(Entry, Entry), T>,
+  module: Entry,
+  segments: &[Entry],
+) -> Option<(&'i (Entry, Entry), &'i T)> {
+  match segments {
+    [name] => items
+      .get_key_value(&(module, *name))
+      .or_else(|| items.iter().find(|((_, item), _)| item == name)),
+    [other, name] => items.get_key_value(&(*other, *name)),
+    _ => None,
+  }
+}
+
+fn walk(dependencies: &[Vec<usize>], chain: &mut Vec<usize>) -> bool {
+  for &next in &dependencies[*chain.last().unwrap()] {
+    if next == chain[0] {
+      chain.push(next);
+      return true;
+    }
+    // Cycles that do not pass through the start are found from their own
+    // members.
+    if chain.contains(&next) {
+      continue;
+    }
+
+    chain.push(next);
+    if walk(dependencies, chain) {
+      return true;
+    }
+    chain.pop();
+  }
+  false
+}
+
+/// Finds a chain of imports leading from the file at `start` back to itself,
+/// such as `[0, 1, 0]` when the first two files import each other.
+pub fn find_cycle(start: usize, dependencies: &[Vec<usize>]) -> Option<Vec<usize>> {
+  let mut chain = vec![start];
+  walk(dependencies, &mut chain).then_some(chain)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::{
+    Path,
+    PathBuf,
+  };
+
+  use indexmap::IndexMap;
+  use sable_common::interner::Entry;
+
+  use super::{
+    find_cycle,
+    import_path,
+    lookup,
+    module_name,
+    normalize,
+  };
+
+  #[test]
+  fn test_module_name() {
+    assert_eq!(module_name("math.sable"), "math");
+    assert_eq!(module_name("src/geo/point.sable"), "point");
+    assert_eq!(module_name("README"), "README");
+  }
+
+  #[test]
+  fn test_import_path() {
+    assert_eq!(normalize(Path::new("./lib/../math.sable")), PathBuf::from("math.sable"));
+    assert_eq!(import_path("src/main.sable", "other.sable"), PathBuf::from("src/other.sable"));
+    assert_eq!(import_path("src/main.sable", "../lib/a.sable"), PathBuf::from("lib/a.sable"));
+    assert_eq!(import_path("main.sable", "./b.sable"), PathBuf::from("b.sable"));
+  }
+
+  #[test]
+  fn test_lookup() {
+    let (a, b, id, other) = (Entry(0), Entry(1), Entry(2), Entry(3));
+    let items = IndexMap::from([((a, id), "a::Id"), ((b, id), "b::Id"), ((b, other), "b::Other")]);
+    let found = |module, segments: &[Entry]| lookup(&items, module, segments).map(|(_, item)| *item);
+
+    assert_eq!(found(a, &[id]), Some("a::Id"));
+    assert_eq!(found(b, &[id]), Some("b::Id"));
+    assert_eq!(found(a, &[b, id]), Some("b::Id"));
+    assert_eq!(found(a, &[other]), Some("b::Other"));
+    assert_eq!(found(a, &[a, other]), None);
+    assert_eq!(found(a, &[a, b, id]), None);
+  }
+
+  #[test]
+  fn test_find_cycle() {
+    assert_eq!(find_cycle(0, &[vec![1], vec![2], vec![]]), None);
+    assert_eq!(find_cycle(0, &[vec![0]]), Some(vec![0, 0]));
+    assert_eq!(find_cycle(0, &[vec![1], vec![2], vec![0]]), Some(vec![0, 1, 2, 0]));
+    // The cycle between 1 and 2 does not lead back to 0.
+    assert_eq!(find_cycle(0, &[vec![1], vec![2], vec![1]]), None);
+    assert_eq!(find_cycle(1, &[vec![1], vec![2], vec![1]]), Some(vec![1, 2, 1]));
+  }
+}
@@ -1 +1,10 @@
+#![cfg_attr(test, feature(allocator_api))]
+pub mod alias;
+pub mod cast;
+pub mod import;
+pub mod monomorphize;
+pub mod resolve_error;
 pub mod resolver;
+pub mod substitute;
+#[cfg(test)]
+mod testing;
\ No newline at end of file
@@ -0,0 +1,1227 @@
+use std::collections::VecDeque;
+
+use getset::Getters;
+use indexmap::{
+  IndexMap,
+  IndexSet,
+};
+use sable_arena::TypedArena;
+use sable_ast::{
+  ast::Ast,
+  expression::{
+    ArrayExpression,
+    AssignExpression,
+    BinaryExpression,
+    BlockExpression,
+    CallExpression,
+    CastExpression,
+    Expression,
+    ExpressionVisitorMut,
+    FieldExpression,
+    IdentifierExpression,
+    IfExpression,
+    IndexExpression,
+    LiteralExpression,
+    LoopExpression,
+    MatchExpression,
+    StructLiteralExpression,
+    UnaryExpression,
+    WhileExpression,
+  },
+  located::Located,
+  objects::{
+    enumeration::{
+      Enum,
+      EnumVariant,
+    },
+    function::{
+      Function,
+      FunctionParam,
+    },
+    structure::{
+      Struct,
+      StructField,
+    },
+  },
+  pattern::{
+    Pattern,
+    PatternKind,
+  },
+  statement::{
+    BreakStatement,
+    ReturnStatement,
+    Statement,
+    StatementVisitorMut,
+    VariableStatement,
+  },
+  types::{
+    Path,
+    Type,
+  },
+};
+use sable_common::{
+  interner::{
+    Entry,
+    StrInterner,
+  },
+  writer::{
+    Reportable,
+    Sink,
+  },
+};
+
+use crate::{
+  alias::{
+    self,
+    Aliases,
+  },
+  import,
+  resolve_error::{
+    ErrorsReported,
+    ResolveError,
+    generic_error::{
+      GenericError,
+      GenericErrorKind,
+    },
+  },
+  substitute::Substituter,
+};
+
+/// Instantiations nested deeper than this are assumed to never terminate, as
+/// in `func f<T>(x: T): T { return f<T*>(&x); }`.
+const MAX_INSTANCE_DEPTH: usize = 64;
+
+enum Status {
+  Ok,
+  Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceKind {
+  Function,
+  Struct,
+}
+
+/// A concrete copy of a generic function or struct, such as `max<i32>`.
+#[derive(Debug, Getters)]
+pub struct Instance<'src> {
+  #[getset(get = "pub")]
+  kind: InstanceKind,
+  /// The module the generic item is defined in, and the instance added to.
+  #[getset(get = "pub")]
+  module: Entry,
+  #[getset(get = "pub")]
+  item: Entry,
+  #[getset(get = "pub")]
+  args: Vec<Type<'src>>,
+  #[getset(get = "pub")]
+  mangled: Entry,
+}
+
+/// A generic function or struct, by the `Ast` that defines it and its index
+/// among that `Ast`'s functions or structs.
+#[derive(Clone)]
+struct Generic {
+  ast: usize,
+  index: usize,
+  params: Vec<Entry>,
+}
+
+/// An instance that still has to be copied from its generic item.
+struct Pending<'src> {
+  kind: InstanceKind,
+  ast: usize,
+  index: usize,
+  substitution: Vec<(Entry, Type<'src>)>,
+  depth: usize,
+  mangled: Entry,
+}
+
+/// Specializes every generic function or struct reachable from non-generic
+/// code. Each instance is copied with its type parameters substituted and
+/// added to the `Ast` of its generic item under a distinct mangled name, and
+/// every use such as `max<i32>` is rewritten to name that copy. The generic
+/// items themselves are left in place.
+pub struct Monomorphizer<'mono, 'ast, 'src, D>
+where
+  D: Sink<'src> + ?Sized,
+{
+  asts: &'mono mut [&'ast mut Ast<'ast, 'src>],
+  intern: &'mono StrInterner<'src>,
+  sink: &'mono mut D,
+  /// The module name of each `Ast`.
+  modules: Vec<Entry>,
+  /// Keyed by module and item name.
+  functions: IndexMap<(Entry, Entry), Generic>,
+  structs: IndexMap<(Entry, Entry), Generic>,
+  /// Module and name of every struct and enum, instances included, so a type
+  /// argument such as `Id` mangles with the module that declares it.
+  types: IndexSet<(Entry, Entry)>,
+  /// Keyed by mangled name, as types compare their source locations.
+  instances: IndexMap<String, Instance<'src>>,
+  queue: VecDeque<Pending<'src>>,
+  /// Type arguments are compared after expanding aliases, so `max<Meters>`
+  /// and `max<f64>` are one instance.
+  aliases: Aliases<'src>,
+  /// Parameters, local variables and pattern bindings, which shadow generic
+  /// functions of the same name.
+  locals: Vec<IndexSet<Entry>>,
+  /// The index of the `Ast` being rewritten.
+  module: usize,
+  depth: usize,
+  status: Status,
+}
+
+impl<'mono, 'ast, 'src, D> Monomorphizer<'mono, 'ast, 'src, D>
+where
+  D: Sink<'src> + ?Sized,
+{
+  pub fn new(
+    asts: &'mono mut [&'ast mut Ast<'ast, 'src>],
+    intern: &'mono StrInterner<'src>,
+    sink: &'mono mut D,
+  ) -> Self {
+    let mut modules = Vec::with_capacity(asts.len());
+    let mut functions = IndexMap::new();
+    let mut structs = IndexMap::new();
+    let mut types = IndexSet::new();
+    let mut aliases = Aliases::new();
+    for (index, ast) in asts.iter().enumerate() {
+      let module = intern.intern(import::module_name(ast.file()));
+      modules.push(module);
+
+      for alias in ast.aliases() {
+        aliases.insert((module, *alias.name().value()), alias.type_().value().clone());
+      }
+      for (position, func) in ast.funcs().iter().enumerate() {
+        if func.is_generic() {
+          let generic = Generic {
+            ast: index,
+            index: position,
+            params: func.generics().iter().map(|param| *param.value()).collect(),
+          };
+          functions.insert((module, *func.name().value()), generic);
+        }
+      }
+      for enumeration in ast.enums() {
+        types.insert((module, *enumeration.name().value()));
+      }
+      for (position, structure) in ast.structs().iter().enumerate() {
+        types.insert((module, *structure.name().value()));
+        if structure.is_generic() {
+          let generic = Generic {
+            ast: index,
+            index: position,
+            params: structure
+              .generics()
+              .iter()
+              .map(|param| *param.value())
+              .collect(),
+          };
+          structs.insert((module, *structure.name().value()), generic);
+        }
+      }
+    }
+
+    Monomorphizer {
+      asts,
+      intern,
+      sink,
+      modules,
+      functions,
+      structs,
+      types,
+      instances: IndexMap::new(),
+      queue: VecDeque::new(),
+      aliases,
+      locals: Vec::new(),
+      module: 0,
+      depth: 0,
+      status: Status::Ok,
+    }
+  }
+
+  pub fn instances(&self) -> impl Iterator<Item = &Instance<'src>> {
+    self.instances.values()
+  }
+
+  /// Looks up the instance a use of `module::item<args>` refers to.
+  pub fn instance(
+    &self,
+    module: Entry,
+    item: Entry,
+    args: &[Type<'src>],
+  ) -> Option<&Instance<'src>> {
+    self.instances.get(&self.mangle(module, item, args))
+  }
+
+  fn report(&mut self, error: GenericError<'src>) {
+    self
+      .sink
+      .report(ResolveError::Generic(error).report())
+      .unwrap();
+    self.status = Status::Error;
+  }
+
+  fn name(&self, entry: Entry) -> &'src str {
+    self.intern.resolve(entry).unwrap()
+  }
+
+  /// Mangles `math::max<i32, u8*>` into `N4math3maxE$3i32P2u8`. Path segments
+  /// are length prefixed, qualified paths and structs and enums, which are
+  /// qualified with their module, are wrapped in `N..E`, type
+  /// arguments in `I..E` and pointers are prefixed with `P`. Arrays become
+  /// `A<len>_` and slices `S` followed by the element type, and function types
+  /// `F<arity>_` followed by the parameter and return types, so distinct
+  /// instances never collide.
+  fn mangle(&self, module: Entry, item: Entry, args: &[Type<'src>]) -> String {
+    let module = self.name(module);
+    let item = self.name(item);
+    let mut mangled = format!("N{}{}{}{}E$", module.len(), module, item.len(), item);
+    for arg in args {
+      self.mangle_type(arg, &mut mangled);
+    }
+    mangled
+  }
+
+  fn mangle_type(&self, ty: &Type<'src>, out: &mut String) {
+    match ty {
+      Type::Infer => unreachable!("Type arguments are always explicit"),
+      Type::Pointer(inner) => {
+        out.push('P');
+        self.mangle_type(inner, out);
+      }
+      Type::Array(inner, len) => {
+        out.push_str(&format!("A{}_", len));
+        self.mangle_type(inner, out);
+      }
+      Type::Slice(inner) => {
+        out.push('S');
+        self.mangle_type(inner, out);
+      }
+      Type::Function(params, return_type) => {
+        out.push_str(&format!("F{}_", params.len()));
+        for param in params {
+          self.mangle_type(param, out);
+        }
+        self.mangle_type(return_type, out);
+      }
+      Type::Path(path) => {
+        let module = match path.segments().as_slice() {
+          [name] => self.type_module(*name.value()),
+          _ => None,
+        };
+        let qualified = module.is_some() || !path.is_single();
+        if qualified {
+          out.push('N');
+        }
+        for segment in module.iter().chain(path.segments().iter().map(|segment| segment.value())) {
+          let name = self.name(*segment);
+          out.push_str(&format!("{}{}", name.len(), name));
+        }
+        if qualified {
+          out.push('E');
+        }
+
+        if !path.generics().is_empty() {
+          out.push('I');
+          for arg in path.generics() {
+            self.mangle_type(arg, out);
+          }
+          out.push('E');
+        }
+      }
+    }
+  }
+
+  /// The module declaring the struct or enum a lone `name` refers to, found
+  /// the way `lookup` finds generic items. `None` for primitives and type
+  /// parameters.
+  fn type_module(&self, name: Entry) -> Option<Entry> {
+    let current = self.modules[self.module];
+    if self.types.contains(&(current, name)) {
+      return Some(current);
+    }
+    self
+      .types
+      .iter()
+      .find(|(_, item)| *item == name)
+      .map(|(module, _)| *module)
+  }
+
+  fn is_local(&self, name: Entry) -> bool {
+    self.locals.iter().any(|scope| scope.contains(&name))
+  }
+
+  fn bind_pattern(&mut self, pattern: &Pattern<'ast, 'src>) {
+    match pattern.kind() {
+      PatternKind::Binding(name) => {
+        if let Some(scope) = self.locals.last_mut() {
+          scope.insert(*name);
+        }
+      }
+      PatternKind::Variant(variant) => {
+        for field in variant.fields().iter() {
+          self.bind_pattern(field);
+        }
+      }
+      PatternKind::Wildcard | PatternKind::Literal(_) => {}
+    }
+  }
+
+  /// Finds the generic item `path` names, if any. `module::item` names an
+  /// item of that module. A lone name prefers the current module, and
+  /// otherwise finds the item in any module, as name resolution does.
+  fn lookup(&self, kind: InstanceKind, path: &Path<'src>) -> Option<Generic> {
+    let generics = match kind {
+      InstanceKind::Function => &self.functions,
+      InstanceKind::Struct => &self.structs,
+    };
+
+    if kind == InstanceKind::Function && path.is_single() && self.is_local(path.name()) {
+      return None;
+    }
+    import::lookup(generics, self.modules[self.module], &path.names())
+      .map(|(_, generic)| generic.clone())
+  }
+
+  /// Records the instance `path` asks for and queues it to be copied if it is
+  /// new. Returns `path` renamed to the instance.
+  fn instantiate(
+    &mut self,
+    kind: InstanceKind,
+    generic: Generic,
+    path: &Path<'src>,
+    args: Vec<Type<'src>>,
+  ) -> Option<Path<'src>> {
+    let item = path.name();
+    if generic.params.len() != args.len() {
+      let kind = GenericErrorKind::ArgumentCount {
+        name: self.name(item),
+        expected: generic.params.len(),
+        found: args.len(),
+      };
+      self.report(GenericError::new(path.location(), kind));
+      return None;
+    }
+
+    let module = self.modules[generic.ast];
+    let mangled = self.mangle(module, item, &args);
+    let entry = match self.instances.get(&mangled) {
+      Some(instance) => instance.mangled,
+      None => {
+        if self.depth >= MAX_INSTANCE_DEPTH {
+          let kind = GenericErrorKind::DepthLimit(self.name(item));
+          self.report(GenericError::new(path.location(), kind));
+          return None;
+        }
+
+        let entry = self.intern.intern(&mangled);
+        if kind == InstanceKind::Struct {
+          self.types.insert((module, entry));
+        }
+        let substitution = generic
+          .params
+          .iter()
+          .copied()
+          .zip(args.iter().cloned())
+          .collect();
+        self.queue.push_back(Pending {
+          kind,
+          ast: generic.ast,
+          index: generic.index,
+          substitution,
+          depth: self.depth + 1,
+          mangled: entry,
+        });
+        self.instances.insert(
+          mangled,
+          Instance {
+            kind,
+            module,
+            item,
+            args,
+            mangled: entry,
+          },
+        );
+        entry
+      }
+    };
+
+    let mut segments = path.segments().clone();
+    let last = segments.last_mut().expect("path without segments");
+    *last = last.replace(entry);
+    Some(Path::builder().segments(segments).build())
+  }
+
+  /// Rewrites a use of `path` that may name a generic item of the given kind.
+  /// Returns `None` if the path stays as it is.
+  fn rewrite_path(&mut self, kind: InstanceKind, path: &Path<'src>) -> Option<Path<'src>> {
+    let args: Vec<_> = path
+      .generics()
+      .iter()
+      .map(|arg| {
+        let arg = alias::expand(arg, self.modules[self.module], &self.aliases);
+        self.rewrite_type(&arg)
+      })
+      .collect();
+
+    match (self.lookup(kind, path), args.is_empty()) {
+      (Some(generic), false) => self.instantiate(kind, generic, path, args),
+      (Some(_), true) => {
+        let kind = GenericErrorKind::MissingArguments(self.name(path.name()));
+        self.report(GenericError::new(path.location(), kind));
+        None
+      }
+      (None, false) => {
+        let kind = GenericErrorKind::NotGeneric(self.name(path.name()));
+        self.report(GenericError::new(path.location(), kind));
+        None
+      }
+      (None, true) => None,
+    }
+  }
+
+  fn rewrite_type(&mut self, ty: &Type<'src>) -> Type<'src> {
+    match ty {
+      Type::Infer => Type::Infer,
+      Type::Pointer(inner) => Type::Pointer(Box::new(self.rewrite_type(inner))),
+      Type::Array(inner, len) => Type::Array(Box::new(self.rewrite_type(inner)), *len),
+      Type::Slice(inner) => Type::Slice(Box::new(self.rewrite_type(inner))),
+      Type::Function(params, return_type) => Type::Function(
+        params
+          .iter()
+          .map(|param| self.rewrite_type(param))
+          .collect(),
+        Box::new(self.rewrite_type(return_type)),
+      ),
+      Type::Path(path) => match self.rewrite_path(InstanceKind::Struct, path) {
+        Some(path) => Type::Path(path),
+        None => ty.clone(),
+      },
+    }
+  }
+
+  /// Returns the rewritten type if it differs from `ty`.
+  fn rewrite_located(
+    &mut self,
+    ty: &Located<'src, Type<'src>>,
+  ) -> Option<Located<'src, Type<'src>>> {
+    let rewritten = self.rewrite_type(ty.value());
+    (rewritten != *ty.value()).then(|| ty.replace(rewritten))
+  }
+
+  fn rewrite_located_mut(&mut self, ty: &mut Located<'src, Type<'src>>) {
+    if let Some(rewritten) = self.rewrite_located(ty) {
+      *ty = rewritten;
+    }
+  }
+
+  fn rewrite_block(&mut self, block: &mut BlockExpression<'ast, 'src>) {
+    self.locals.push(IndexSet::new());
+    for stmt in block.body_mut() {
+      self.visit_stmt_mut(stmt);
+    }
+    if let Some(tail) = block.tail_mut() {
+      self.visit_expr_mut(tail);
+    }
+    self.locals.pop();
+  }
+
+  fn rewrite_function(
+    &mut self,
+    func: &mut Function<'ast, 'src>,
+    param_arena: &'ast TypedArena<FunctionParam<'src>>,
+  ) {
+    let params = *func.params();
+    let types: Vec<_> = params
+      .iter()
+      .map(|param| self.rewrite_located(param.type_()))
+      .collect();
+    if types.iter().any(Option::is_some) {
+      let mut types = types.into_iter();
+      let params = param_arena.alloc_slice_with(params.len(), |index| {
+        let type_ = types.next().unwrap();
+        FunctionParam::builder()
+          .name(params[index].name().clone())
+          .type_(type_.unwrap_or_else(|| params[index].type_().clone()))
+          .build()
+      });
+      func.set_params(params);
+    }
+    self.rewrite_located_mut(func.return_type_mut());
+
+    self
+      .locals
+      .push(params.iter().map(|param| *param.name().value()).collect());
+    if let Some(block) = func.block_mut() {
+      self.rewrite_block(block);
+    }
+    self.locals.pop();
+  }
+
+  fn rewrite_struct(
+    &mut self,
+    structure: &mut Struct<'ast, 'src>,
+    field_arena: &'ast TypedArena<StructField<'src>>,
+  ) {
+    let fields = *structure.fields();
+    let types: Vec<_> = fields
+      .iter()
+      .map(|field| self.rewrite_located(field.type_()))
+      .collect();
+    if types.iter().any(Option::is_some) {
+      let mut types = types.into_iter();
+      let fields = field_arena.alloc_slice_with(fields.len(), |index| {
+        let type_ = types.next().unwrap();
+        StructField::builder()
+          .name(fields[index].name().clone())
+          .type_(type_.unwrap_or_else(|| fields[index].type_().clone()))
+          .build()
+      });
+      structure.set_fields(fields);
+    }
+  }
+
+  fn rewrite_enum(
+    &mut self,
+    enumeration: &mut Enum<'ast, 'src>,
+    variant_arena: &'ast TypedArena<EnumVariant<'ast, 'src>>,
+    payload_arena: &'ast TypedArena<Located<'src, Type<'src>>>,
+  ) {
+    let variants = *enumeration.variants();
+    let payloads: Vec<Vec<_>> = variants
+      .iter()
+      .map(|variant| {
+        variant
+          .payload()
+          .iter()
+          .map(|ty| self.rewrite_located(ty))
+          .collect()
+      })
+      .collect();
+    if payloads.iter().flatten().all(Option::is_none) {
+      return;
+    }
+
+    let rewritten: Vec<_> = variants
+      .iter()
+      .zip(payloads)
+      .map(|(variant, payload)| {
+        let old = *variant.payload();
+        let mut payload = payload.into_iter();
+        let payload = payload_arena.alloc_slice_with(old.len(), |index| {
+          payload
+            .next()
+            .unwrap()
+            .unwrap_or_else(|| old[index].clone())
+        });
+        EnumVariant::builder()
+          .name(variant.name().clone())
+          .payload(payload)
+          .build()
+      })
+      .collect();
+    let mut rewritten = rewritten.into_iter();
+    let variants = variant_arena.alloc_slice_with(variants.len(), |_| rewritten.next().unwrap());
+    enumeration.set_variants(variants);
+  }
+
+  /// Rewrites the uses of generic items in every non-generic item of `ast`.
+  fn rewrite_ast(&mut self, ast: &mut Ast<'ast, 'src>) {
+    let param_arena = *ast.param_arena();
+    let field_arena = *ast.field_arena();
+    let variant_arena = *ast.variant_arena();
+    let payload_arena = *ast.payload_arena();
+
+    for func in ast.funcs_mut().iter_mut().filter(|func| !func.is_generic()) {
+      self.rewrite_function(func, param_arena);
+    }
+    for structure in ast
+      .structs_mut()
+      .iter_mut()
+      .filter(|structure| !structure.is_generic())
+    {
+      self.rewrite_struct(structure, field_arena);
+    }
+    for global in ast.globals_mut() {
+      self.rewrite_located_mut(global.type__mut());
+      self.visit_expr_mut(global.initializer_mut());
+    }
+    for alias in ast.aliases_mut() {
+      self.rewrite_located_mut(alias.type__mut());
+    }
+    for enumeration in ast.enums_mut() {
+      self.rewrite_enum(enumeration, variant_arena, payload_arena);
+    }
+  }
+
+  /// Copies the generic function of `pending` with its type parameters
+  /// substituted.
+  fn function_instance(&self, pending: &Pending<'src>) -> Function<'ast, 'src> {
+    let ast = &self.asts[pending.ast];
+    let template = &ast.funcs()[pending.index];
+    let mut substituter = Substituter::new(ast, &pending.substitution);
+
+    let params: Vec<_> = template
+      .params()
+      .iter()
+      .map(|param| {
+        FunctionParam::builder()
+          .name(param.name().clone())
+          .type_(substituter.type_(param.type_()))
+          .build()
+      })
+      .collect();
+    let mut params = params.into_iter();
+    let params = ast
+      .param_arena()
+      .alloc_slice_with(template.params().len(), |_| params.next().unwrap());
+
+    Function::builder()
+      .name(template.name().replace(pending.mangled))
+      .generics(Vec::new())
+      .params(params)
+      .return_type(substituter.type_(template.return_type()))
+      .block(
+        template
+          .block()
+          .as_ref()
+          .map(|block| substituter.copy_block(block)),
+      )
+      .abi(template.abi().clone())
+      .variadic(*template.variadic())
+      .docs(template.docs().clone())
+      .build()
+  }
+
+  /// Copies the generic struct of `pending` with its type parameters
+  /// substituted.
+  fn struct_instance(&self, pending: &Pending<'src>) -> Struct<'ast, 'src> {
+    let ast = &self.asts[pending.ast];
+    let template = &ast.structs()[pending.index];
+    let substituter = Substituter::new(ast, &pending.substitution);
+
+    let fields: Vec<_> = template
+      .fields()
+      .iter()
+      .map(|field| {
+        StructField::builder()
+          .name(field.name().clone())
+          .type_(substituter.type_(field.type_()))
+          .build()
+      })
+      .collect();
+    let mut fields = fields.into_iter();
+    let fields = ast
+      .field_arena()
+      .alloc_slice_with(template.fields().len(), |_| fields.next().unwrap());
+
+    Struct::builder()
+      .name(template.name().replace(pending.mangled))
+      .generics(Vec::new())
+      .fields(fields)
+      .docs(template.docs().clone())
+      .build()
+  }
+
+  pub fn monomorphize(&mut self) -> Result<(), ErrorsReported> {
+    let asts = std::mem::take(&mut self.asts);
+    for (module, ast) in asts.iter_mut().enumerate() {
+      self.module = module;
+      self.rewrite_ast(ast);
+    }
+    self.asts = asts;
+
+    // Instances are copied and rewritten in turn, which may queue further
+    // instances such as the `Pair<i32>` used by `wrap<i32>`.
+    while let Some(pending) = self.queue.pop_front() {
+      self.module = pending.ast;
+      self.depth = pending.depth;
+      match pending.kind {
+        InstanceKind::Function => {
+          let mut func = self.function_instance(&pending);
+          let param_arena = *self.asts[pending.ast].param_arena();
+          self.rewrite_function(&mut func, param_arena);
+          self.asts[pending.ast].funcs_mut().push(func);
+        }
+        InstanceKind::Struct => {
+          let mut structure = self.struct_instance(&pending);
+          let field_arena = *self.asts[pending.ast].field_arena();
+          self.rewrite_struct(&mut structure, field_arena);
+          self.asts[pending.ast].structs_mut().push(structure);
+        }
+      }
+    }
+
+    match self.status {
+      Status::Ok => Ok(()),
+      Status::Error => Err(ErrorsReported),
+    }
+  }
+}
+
+impl<'mono, 'ast, 'src, D> ExpressionVisitorMut<'ast, 'src> for Monomorphizer<'mono, 'ast, 'src, D>
+where
+  D: Sink<'src> + ?Sized,
+{
+  type VisitReturn = ();
+
+  fn visit_block_mut(
+    &mut self,
+    block: &mut BlockExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.rewrite_block(block);
+  }
+
+  fn visit_literal_mut(
+    &mut self,
+    _literal: &mut LiteralExpression,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+  }
+
+  fn visit_assign_mut(
+    &mut self,
+    assign: &mut AssignExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(assign.target_mut());
+    self.visit_expr_mut(assign.value_mut());
+  }
+
+  fn visit_binary_mut(
+    &mut self,
+    binary: &mut BinaryExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(binary.lhs_mut());
+    self.visit_expr_mut(binary.rhs_mut());
+  }
+
+  fn visit_unary_mut(
+    &mut self,
+    unary: &mut UnaryExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(unary.operand_mut());
+  }
+
+  fn visit_call_mut(
+    &mut self,
+    call: &mut CallExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(call.callee_mut());
+    for arg in call.args_mut().iter_mut() {
+      self.visit_expr_mut(arg);
+    }
+  }
+
+  fn visit_if_mut(
+    &mut self,
+    if_: &mut IfExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(if_.condition_mut());
+    self.rewrite_block(if_.then_block_mut());
+    if let Some(else_branch) = if_.else_branch_mut() {
+      self.visit_expr_mut(else_branch);
+    }
+  }
+
+  fn visit_while_mut(
+    &mut self,
+    while_: &mut WhileExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(while_.condition_mut());
+    self.rewrite_block(while_.body_mut());
+  }
+
+  fn visit_loop_mut(
+    &mut self,
+    loop_: &mut LoopExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.rewrite_block(loop_.body_mut());
+  }
+
+  fn visit_match_mut(
+    &mut self,
+    match_: &mut MatchExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(match_.scrutinee_mut());
+    for arm in match_.arms_mut().iter_mut() {
+      self.locals.push(IndexSet::new());
+      self.bind_pattern(arm.pattern());
+      self.visit_expr_mut(arm.body_mut());
+      self.locals.pop();
+    }
+  }
+
+  fn visit_struct_literal_mut(
+    &mut self,
+    struct_literal: &mut StructLiteralExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    if let Some(path) = self.rewrite_path(InstanceKind::Struct, struct_literal.path()) {
+      *struct_literal.path_mut() = path;
+    }
+    for field in struct_literal.fields_mut().iter_mut() {
+      self.visit_expr_mut(field.value_mut());
+    }
+  }
+
+  fn visit_field_mut(
+    &mut self,
+    field: &mut FieldExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(field.object_mut());
+  }
+
+  fn visit_array_mut(
+    &mut self,
+    array: &mut ArrayExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    match array {
+      ArrayExpression::List(list) => {
+        for element in list.elements_mut().iter_mut() {
+          self.visit_expr_mut(element);
+        }
+      }
+      ArrayExpression::Repeat(repeat) => {
+        self.visit_expr_mut(repeat.value_mut());
+        self.visit_expr_mut(repeat.count_mut());
+      }
+    }
+  }
+
+  fn visit_index_mut(
+    &mut self,
+    index: &mut IndexExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(index.object_mut());
+    self.visit_expr_mut(index.index_mut());
+  }
+
+  fn visit_cast_mut(
+    &mut self,
+    cast: &mut CastExpression<'ast, 'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(cast.value_mut());
+    self.rewrite_located_mut(cast.type__mut());
+  }
+
+  fn visit_identifier_mut(
+    &mut self,
+    identifier: &mut IdentifierExpression<'src>,
+    _expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    if let Some(path) = self.rewrite_path(InstanceKind::Function, identifier.path()) {
+      identifier.path = path;
+    }
+  }
+}
+
+impl<'mono, 'ast, 'src, D> StatementVisitorMut<'ast, 'src> for Monomorphizer<'mono, 'ast, 'src, D>
+where
+  D: Sink<'src> + ?Sized,
+{
+  type VisitReturn = ();
+
+  fn visit_expression_mut(
+    &mut self,
+    expr: &mut Expression<'ast, 'src>,
+    _statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.visit_expr_mut(expr);
+  }
+
+  fn visit_variable_mut(
+    &mut self,
+    variable: &mut VariableStatement<'ast, 'src>,
+    _statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    self.rewrite_located_mut(variable.type__mut());
+    self.visit_expr_mut(variable.initializer_mut());
+    if let Some(scope) = self.locals.last_mut() {
+      scope.insert(*variable.name().value());
+    }
+  }
+
+  fn visit_return_mut(
+    &mut self,
+    return_: &mut ReturnStatement<'ast, 'src>,
+    _statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    if let Some(value) = return_.value_mut() {
+      self.visit_expr_mut(value);
+    }
+  }
+
+  fn visit_break_mut(
+    &mut self,
+    break_: &mut BreakStatement<'ast, 'src>,
+    _statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    if let Some(value) = break_.value_mut() {
+      self.visit_expr_mut(value);
+    }
+  }
+
+  fn visit_continue_mut(&mut self, _statement: &mut Statement<'ast, 'src>) -> Self::VisitReturn {}
+}
+
+#[cfg(test)]
+mod tests {
+  use sable_ast::{
+    expression::ExpressionKind,
+    types::Type,
+  };
+
+  use crate::testing::{
+    Resolved,
+    monomorphize,
+  };
+
+  const ID: &str = "func id<T>(x: T): T { x }";
+
+  /// The functions of the file at `ast`, instances included.
+  fn funcs<'src>(resolved: &Resolved<'_, '_, 'src>, ast: usize) -> Vec<&'src str> {
+    resolved.asts[ast]
+      .funcs()
+      .iter()
+      .map(|func| resolved.name(*func.name().value()))
+      .collect()
+  }
+
+  /// The function called by the tail of `name`, qualified as it is written.
+  fn tail_callee(resolved: &Resolved<'_, '_, '_>, name: &str) -> String {
+    let func = resolved
+      .asts
+      .iter()
+      .flat_map(|ast| ast.funcs().iter())
+      .find(|func| resolved.name(*func.name().value()) == name)
+      .unwrap();
+    let tail = func.block().as_ref().unwrap().tail().as_ref().unwrap();
+    let ExpressionKind::Call(call) = tail.kind() else {
+      panic!("tail of `{}` is not a call", name);
+    };
+    let ExpressionKind::Identifier(callee) = call.callee().kind() else {
+      panic!("callee of `{}` is not a path", name);
+    };
+    callee
+      .path()
+      .segments()
+      .iter()
+      .map(|segment| resolved.name(*segment.value()))
+      .collect::<Vec<_>>()
+      .join("::")
+  }
+
+  fn render_type(resolved: &Resolved<'_, '_, '_>, ty: &Type<'_>) -> String {
+    match ty {
+      Type::Infer => "_".to_string(),
+      Type::Path(path) => {
+        let mut rendered = path
+          .segments()
+          .iter()
+          .map(|segment| resolved.name(*segment.value()))
+          .collect::<Vec<_>>()
+          .join("::");
+        if !path.generics().is_empty() {
+          let args: Vec<_> = path
+            .generics()
+            .iter()
+            .map(|arg| render_type(resolved, arg))
+            .collect();
+          rendered = format!("{}<{}>", rendered, args.join(", "));
+        }
+        rendered
+      }
+      Type::Pointer(inner) => format!("{}*", render_type(resolved, inner)),
+      Type::Array(inner, length) => format!("[{}; {}]", render_type(resolved, inner), length),
+      Type::Slice(inner) => format!("[{}]", render_type(resolved, inner)),
+      Type::Function(params, return_type) => {
+        let params: Vec<_> = params
+          .iter()
+          .map(|param| render_type(resolved, param))
+          .collect();
+        format!("func({}): {}", params.join(", "), render_type(resolved, return_type))
+      }
+    }
+  }
+
+  #[test]
+  fn test_mangling() {
+    let main = format!(
+      "{} func f(p: u8*): u8* {{ id<u8*>(p) }} func g(): i32 {{ id<i32>(1) }}",
+      ID
+    );
+    monomorphize(&[("main.sable", &main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      assert_eq!(
+        funcs(resolved, 0),
+        ["id", "f", "g", "N4main2idE$P2u8", "N4main2idE$3i32"]
+      );
+      assert_eq!(tail_callee(resolved, "f"), "N4main2idE$P2u8");
+      assert_eq!(tail_callee(resolved, "g"), "N4main2idE$3i32");
+
+      let instance = &resolved.asts[0].funcs()[3];
+      assert!(!instance.is_generic());
+      assert_eq!(
+        render_type(resolved, instance.params()[0].type_().value()),
+        "u8*"
+      );
+      assert_eq!(render_type(resolved, instance.return_type().value()), "u8*");
+    });
+  }
+
+  #[test]
+  fn test_module_qualified_generics() {
+    let main = format!(
+      "import util; {} func f(): i32 {{ util::id<i32>(1) }} func g(): i32 {{ id<i32>(2) }}",
+      ID
+    );
+    monomorphize(&[("util.sable", ID), ("main.sable", &main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      assert_eq!(funcs(resolved, 0), ["id", "N4util2idE$3i32"]);
+      assert_eq!(funcs(resolved, 1), ["id", "f", "g", "N4main2idE$3i32"]);
+      assert_eq!(tail_callee(resolved, "f"), "util::N4util2idE$3i32");
+      assert_eq!(tail_callee(resolved, "g"), "N4main2idE$3i32");
+    });
+  }
+
+  #[test]
+  fn test_nominal_arguments() {
+    let main = format!(
+      "import a; import b; struct Id {{ v: i32 }} {}
+      func f(x: a::Id): a::Id {{ id<a::Id>(x) }}
+      func g(x: b::Id): b::Id {{ id<b::Id>(x) }}
+      func h(x: Id): Id {{ id<Id>(x) }}
+      func k(x: [Id*; 2]): [Id*; 2] {{ id<[Id*; 2]>(x) }}",
+      ID
+    );
+    let a = "struct Id { v: i32 }";
+    let b = "struct Id { v: u8 }";
+    let files = [("a.sable", a), ("b.sable", b), ("main.sable", main.as_str())];
+    monomorphize(&files, |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      assert_eq!(tail_callee(resolved, "f"), "N4main2idE$N1a2IdE");
+      assert_eq!(tail_callee(resolved, "g"), "N4main2idE$N1b2IdE");
+      assert_eq!(tail_callee(resolved, "h"), "N4main2idE$N4main2IdE");
+      assert_eq!(tail_callee(resolved, "k"), "N4main2idE$A2_PN4main2IdE");
+
+      let instance = resolved.asts[2].funcs().last().unwrap();
+      assert_eq!(
+        render_type(resolved, instance.params()[0].type_().value()),
+        "[Id*; 2]"
+      );
+    });
+  }
+
+  #[test]
+  fn test_module_aliases() {
+    let main = format!(
+      "import a; import b; {}
+      func f(): i32 {{ id<a::Id>(1) }} func g(): f64 {{ id<b::Id>(1.0) }}",
+      ID
+    );
+    let files = [
+      ("a.sable", "type Id = i32;"),
+      ("b.sable", "type Id = f64;"),
+      ("main.sable", main.as_str()),
+    ];
+    monomorphize(&files, |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      assert_eq!(tail_callee(resolved, "f"), "N4main2idE$3i32");
+      assert_eq!(tail_callee(resolved, "g"), "N4main2idE$3f64");
+    });
+  }
+
+  #[test]
+  fn test_nested_instances() {
+    let main = "struct Pair<T> { a: T, b: T }
+      func wrap<T>(x: T): T { var p: Pair<T> = Pair<T> { a: x, b: x }; id<T>(p.a) }
+      func f(): f64 { wrap<f64>(1.0) }";
+    let main = format!("{} {}", ID, main);
+    monomorphize(&[("main.sable", &main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      assert_eq!(
+        funcs(resolved, 0),
+        ["id", "wrap", "f", "N4main4wrapE$3f64", "N4main2idE$3f64"]
+      );
+      assert_eq!(
+        tail_callee(resolved, "N4main4wrapE$3f64"),
+        "N4main2idE$3f64"
+      );
+
+      let structs: Vec<_> = resolved.asts[0]
+        .structs()
+        .iter()
+        .map(|structure| resolved.name(*structure.name().value()))
+        .collect();
+      assert_eq!(structs, ["Pair", "N4main4PairE$3f64"]);
+      let pair = &resolved.asts[0].structs()[1];
+      assert_eq!(
+        render_type(resolved, pair.fields()[0].type_().value()),
+        "f64"
+      );
+    });
+  }
+
+  #[test]
+  fn test_depth_limit() {
+    let main = "func f<T>(x: T): i32 { f<T*>(&x) } func g(): i32 { f<i32>(1) }";
+    monomorphize(&[("main.sable", main)], |resolved| {
+      assert!(!resolved.ok);
+      assert_eq!(resolved.reports.0.len(), 1, "{:#?}", resolved.reports.0);
+      assert!(
+        resolved
+          .reports
+          .contains("Instantiating `f` recurses without end")
+      );
+    });
+  }
+
+  #[test]
+  fn test_instance_checks() {
+    let main = "func conv<T>(x: T): bool { x as bool } func f(): bool { conv<f64>(1.0) }";
+    monomorphize(&[("main.sable", main)], |resolved| {
+      assert!(!resolved.ok);
+      assert!(resolved.reports.contains("Invalid cast"), "{:#?}", resolved.reports.0);
+    });
+
+    let main = "func conv<T>(x: T): i64 { x as i64 } func f(): i64 { conv<u8>(1) }";
+    monomorphize(&[("main.sable", main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      let instance = resolved.asts[0].funcs().last().unwrap();
+      let tail = instance.block().as_ref().unwrap().tail().as_ref().unwrap();
+      assert!(tail.id().get().is_some());
+    });
+  }
+
+  #[test]
+  fn test_shadowing() {
+    let main = format!(
+      "{} func f(id: i32): i32 {{ id }} func g(): i32 {{ var id: i32 = 1; id }}",
+      ID
+    );
+    monomorphize(&[("main.sable", &main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+      assert_eq!(funcs(resolved, 0), ["id", "f", "g"]);
+    });
+
+    let main = format!("{} func f(): i32 {{ var x: i32 = 1; id(x) }}", ID);
+    monomorphize(&[("main.sable", &main)], |resolved| {
+      assert!(resolved.reports.contains("Missing type arguments for `id`"));
+    });
+  }
+}
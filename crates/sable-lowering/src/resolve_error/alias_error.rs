@@ -0,0 +1,57 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone)]
+pub enum AliasErrorKind<'src> {
+  Redefined(&'src str),
+  /// The aliases passed through, starting and ending with the reported one.
+  Cycle(Vec<&'src str>),
+}
+
+#[derive(Debug)]
+pub struct AliasError<'src> {
+  location: Location<'src>,
+  kind: AliasErrorKind<'src>,
+}
+
+impl<'src> AliasError<'src> {
+  pub fn new(location: Location<'src>, kind: AliasErrorKind<'src>) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for AliasError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let report = build_report(ReportKind::Error, span.clone());
+    match &self.kind {
+      AliasErrorKind::Redefined(name) => report
+        .with_message(format!("Type alias `{}` is defined more than once", name))
+        .with_label(Label::new(span).with_message("Redefined here")),
+      AliasErrorKind::Cycle(chain) => {
+        let path = chain
+          .iter()
+          .map(|name| format!("`{}`", name))
+          .collect::<Vec<_>>()
+          .join(" -> ");
+        report
+          .with_message(format!("Type alias `{}` refers to itself", chain[0]))
+          .with_label(Label::new(span).with_message(format!("Expands through {}", path)))
+          .with_help("Aliases are replaced by what they name; use a struct for recursive types")
+      }
+    }
+    .finish()
+  }
+}
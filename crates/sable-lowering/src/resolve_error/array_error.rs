@@ -0,0 +1,53 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum ArrayErrorKind {
+  OutOfBounds { length: u64, index: i64 },
+  NonConstantCount,
+}
+
+#[derive(Debug)]
+pub struct ArrayError<'src> {
+  location: Location<'src>,
+  kind: ArrayErrorKind,
+}
+
+impl<'src> ArrayError<'src> {
+  pub fn new(location: Location<'src>, kind: ArrayErrorKind) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for ArrayError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      ArrayErrorKind::OutOfBounds { length, index } => (
+        "Index out of bounds".to_string(),
+        format!("The length is {} but the index is {}", length, index),
+      ),
+      ArrayErrorKind::NonConstantCount => (
+        "Array repeat count is not a compile-time constant".to_string(),
+        "This expression cannot be evaluated at compile time".to_string(),
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
@@ -0,0 +1,54 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone)]
+pub enum CallErrorKind {
+  /// The callee has this (already quoted) non-function type.
+  NotCallable(String),
+  ArgumentCount { expected: usize, found: usize },
+}
+
+#[derive(Debug)]
+pub struct CallError<'src> {
+  location: Location<'src>,
+  kind: CallErrorKind,
+}
+
+impl<'src> CallError<'src> {
+  pub fn new(location: Location<'src>, kind: CallErrorKind) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for CallError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match &self.kind {
+      CallErrorKind::NotCallable(ty) => (
+        "Call of a value that is not a function".to_string(),
+        format!("This has type {}", ty),
+      ),
+      CallErrorKind::ArgumentCount { expected, found } => (
+        "Wrong number of arguments".to_string(),
+        format!("Expected {} argument(s) but found {}", expected, found),
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
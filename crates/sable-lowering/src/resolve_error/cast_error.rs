@@ -0,0 +1,43 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug)]
+pub struct CastError<'src> {
+  location: Location<'src>,
+  from: String,
+  to: String,
+}
+
+impl<'src> CastError<'src> {
+  pub fn new(location: Location<'src>, from: String, to: String) -> Self {
+    Self { location, from, to }
+  }
+}
+
+impl<'src> Reportable<'src> for CastError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(format!("Invalid cast from {} to {}", self.from, self.to))
+      .with_label(
+        Label::new(span)
+          .with_message(format!("{} cannot be converted to {} with `as`", self.from, self.to)),
+      )
+      .with_help(
+        "`as` converts between integers, floats and pointers, `u8` to `char` and `bool` to integers",
+      )
+      .finish()
+  }
+}
@@ -0,0 +1,37 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug)]
+pub struct ConditionError<'src> {
+  location: Location<'src>,
+  found: String,
+}
+
+impl<'src> ConditionError<'src> {
+  pub fn new(location: Location<'src>, found: String) -> Self {
+    Self { location, found }
+  }
+}
+
+impl<'src> Reportable<'src> for ConditionError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message("Condition is not a `bool`")
+      .with_label(Label::new(span).with_message(format!("This has type {}", self.found)))
+      .with_help("Compare explicitly, e.g. `x != 0`")
+      .finish()
+  }
+}
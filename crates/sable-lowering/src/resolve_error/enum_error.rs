@@ -0,0 +1,48 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone)]
+pub enum EnumErrorKind<'src> {
+  Redefined(&'src str),
+}
+
+#[derive(Debug)]
+pub struct EnumError<'src> {
+  location: Location<'src>,
+  kind: EnumErrorKind<'src>,
+}
+
+impl<'src> EnumError<'src> {
+  pub fn new(location: Location<'src>, kind: EnumErrorKind<'src>) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for EnumError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match &self.kind {
+      EnumErrorKind::Redefined(name) => (
+        format!("Enum `{}` is defined more than once", name),
+        "Redefined here",
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
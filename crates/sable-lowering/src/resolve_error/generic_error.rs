@@ -0,0 +1,71 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum GenericErrorKind<'src> {
+  MissingArguments(&'src str),
+  NotGeneric(&'src str),
+  ArgumentCount {
+    name: &'src str,
+    expected: usize,
+    found: usize,
+  },
+  DepthLimit(&'src str),
+}
+
+#[derive(Debug)]
+pub struct GenericError<'src> {
+  location: Location<'src>,
+  kind: GenericErrorKind<'src>,
+}
+
+impl<'src> GenericError<'src> {
+  pub fn new(location: Location<'src>, kind: GenericErrorKind<'src>) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for GenericError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      GenericErrorKind::MissingArguments(name) => (
+        format!("Missing type arguments for `{}`", name),
+        format!("Write the type arguments explicitly, e.g. `{}<i32>`", name),
+      ),
+      GenericErrorKind::NotGeneric(name) => (
+        format!("`{}` does not take type arguments", name),
+        "Remove the type arguments".to_string(),
+      ),
+      GenericErrorKind::ArgumentCount {
+        name,
+        expected,
+        found,
+      } => (
+        format!("Wrong number of type arguments for `{}`", name),
+        format!("Expected {} type argument(s), found {}", expected, found),
+      ),
+      GenericErrorKind::DepthLimit(name) => (
+        format!("Instantiating `{}` recurses without end", name),
+        "Each instantiation of this item creates another, deeper one".to_string(),
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
@@ -0,0 +1,53 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum GlobalErrorKind<'src> {
+  Redefined(&'src str),
+  NonConstant,
+}
+
+#[derive(Debug)]
+pub struct GlobalError<'src> {
+  location: Location<'src>,
+  kind: GlobalErrorKind<'src>,
+}
+
+impl<'src> GlobalError<'src> {
+  pub fn new(location: Location<'src>, kind: GlobalErrorKind<'src>) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for GlobalError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      GlobalErrorKind::Redefined(name) => (
+        format!("Global `{}` is defined more than once", name),
+        "Redefined here".to_string(),
+      ),
+      GlobalErrorKind::NonConstant => (
+        "Constant initializer is not a compile-time constant".to_string(),
+        "This expression cannot be evaluated at compile time".to_string(),
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
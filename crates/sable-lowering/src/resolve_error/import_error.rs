@@ -0,0 +1,69 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone)]
+pub enum ImportErrorKind<'src> {
+  /// No file being compiled has this module name or path.
+  UnknownModule(&'src str),
+  /// A path refers into a module the file does not import.
+  NotImported(&'src str),
+  UnknownItem { module: &'src str, item: &'src str },
+  /// The modules passed through, starting and ending with the importing one.
+  Cycle(Vec<&'src str>),
+}
+
+#[derive(Debug)]
+pub struct ImportError<'src> {
+  location: Location<'src>,
+  kind: ImportErrorKind<'src>,
+}
+
+impl<'src> ImportError<'src> {
+  pub fn new(location: Location<'src>, kind: ImportErrorKind<'src>) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for ImportError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let report = build_report(ReportKind::Error, span.clone());
+    match &self.kind {
+      ImportErrorKind::UnknownModule(module) => report
+        .with_message(format!("Unknown module `{}`", module))
+        .with_label(Label::new(span).with_message("No file being compiled has this name"))
+        .with_help("Pass the file to the compiler along with this one"),
+      ImportErrorKind::NotImported(module) => report
+        .with_message(format!("Module `{}` is not imported", module))
+        .with_label(Label::new(span).with_message("Used here"))
+        .with_help(format!("Add `import {};` to this file", module)),
+      ImportErrorKind::UnknownItem { module, item } => report
+        .with_message(format!("Module `{}` has no item `{}`", module, item))
+        .with_label(Label::new(span).with_message("Not found")),
+      ImportErrorKind::Cycle(chain) => {
+        let path = chain
+          .iter()
+          .map(|name| format!("`{}`", name))
+          .collect::<Vec<_>>()
+          .join(" -> ");
+        report
+          .with_message(format!("Module `{}` imports itself", chain[0]))
+          .with_label(Label::new(span).with_message(format!("Imports through {}", path)))
+          .with_help("Move the items both modules need into a module of their own")
+      }
+    }
+    .finish()
+  }
+}
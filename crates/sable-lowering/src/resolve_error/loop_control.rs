@@ -0,0 +1,58 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum LoopControlKind {
+  Break,
+  Continue,
+  BreakWithValue,
+}
+
+#[derive(Debug)]
+pub struct LoopControlError<'src> {
+  location: Location<'src>,
+  kind: LoopControlKind,
+}
+
+impl<'src> LoopControlError<'src> {
+  pub fn new(location: Location<'src>, kind: LoopControlKind) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for LoopControlError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      LoopControlKind::Break => (
+        "`break` outside of a loop",
+        "`break` can only be used inside `while` or `loop`",
+      ),
+      LoopControlKind::Continue => (
+        "`continue` outside of a loop",
+        "`continue` can only be used inside `while` or `loop`",
+      ),
+      LoopControlKind::BreakWithValue => (
+        "`break` with a value inside a `while` loop",
+        "Only `loop` can produce a value with `break`",
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
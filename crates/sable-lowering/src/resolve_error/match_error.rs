@@ -0,0 +1,117 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug)]
+pub enum MatchErrorKind {
+  /// Variants (or `_` for open types such as integers) no arm covers.
+  NonExhaustive(Vec<String>),
+  UnknownEnum(String),
+  UnknownVariant(String),
+  /// A bare variant name defined by more than one enum.
+  AmbiguousVariant {
+    variant: String,
+    enums: Vec<String>,
+  },
+  Arity {
+    variant: String,
+    expected: usize,
+    found: usize,
+  },
+  /// A bare name spelled like a unit variant, such as `Red =>`, which binds
+  /// every value instead of matching the variant.
+  ShadowedVariant { binding: String, variant: String },
+}
+
+#[derive(Debug)]
+pub struct MatchError<'src> {
+  location: Location<'src>,
+  kind: MatchErrorKind,
+}
+
+impl<'src> MatchError<'src> {
+  pub fn new(location: Location<'src>, kind: MatchErrorKind) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for MatchError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match &self.kind {
+      MatchErrorKind::NonExhaustive(missing) => {
+        let missing = missing
+          .iter()
+          .map(|pattern| format!("`{}`", pattern))
+          .collect::<Vec<_>>()
+          .join(", ");
+        (
+          "Non-exhaustive match".to_string(),
+          format!("Patterns not covered: {}", missing),
+        )
+      }
+      MatchErrorKind::UnknownEnum(name) => (
+        format!("Unknown enum `{}`", name),
+        "No enum with this name is defined".to_string(),
+      ),
+      MatchErrorKind::UnknownVariant(name) => (
+        format!("Unknown variant `{}`", name),
+        "This variant is not defined".to_string(),
+      ),
+      MatchErrorKind::AmbiguousVariant { variant, enums } => {
+        let enums = enums
+          .iter()
+          .map(|name| format!("`{}`", name))
+          .collect::<Vec<_>>()
+          .join(", ");
+        (
+          format!("Ambiguous variant `{}`", variant),
+          format!("Defined by {}", enums),
+        )
+      }
+      MatchErrorKind::Arity {
+        variant,
+        expected,
+        found,
+      } => (
+        format!("Wrong number of fields for `{}`", variant),
+        format!("Expected {} field(s), found {}", expected, found),
+      ),
+      MatchErrorKind::ShadowedVariant { binding, variant } => (
+        format!("Binding `{}` shadows the variant `{}`", binding, variant),
+        format!("This matches any value, not only `{}`", variant),
+      ),
+    };
+
+    let mut report = build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label));
+    match self.kind {
+      MatchErrorKind::NonExhaustive(_) => {
+        report = report.with_help("Add an arm for each missing pattern or a wildcard `_` arm");
+      }
+      MatchErrorKind::AmbiguousVariant { .. } => {
+        report = report.with_help("Qualify the variant with its enum, as in `Enum::Variant`");
+      }
+      MatchErrorKind::ShadowedVariant { ref variant, .. } => {
+        report = report.with_help(format!(
+          "Write `{}` to match the variant, or rename the binding",
+          variant
+        ));
+      }
+      _ => {}
+    }
+    report.finish()
+  }
+}
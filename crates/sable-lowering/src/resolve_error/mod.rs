@@ -0,0 +1,68 @@
+pub mod alias_error;
+pub mod array_error;
+pub mod call_error;
+pub mod cast_error;
+pub mod condition_error;
+pub mod enum_error;
+pub mod generic_error;
+pub mod global_error;
+pub mod import_error;
+pub mod loop_control;
+pub mod match_error;
+
+use ariadne::Report;
+use sable_common::{
+  file::Span,
+  writer::Reportable,
+};
+
+use crate::resolve_error::{
+  alias_error::AliasError,
+  array_error::ArrayError,
+  call_error::CallError,
+  cast_error::CastError,
+  condition_error::ConditionError,
+  enum_error::EnumError,
+  generic_error::GenericError,
+  global_error::GlobalError,
+  import_error::ImportError,
+  loop_control::LoopControlError,
+  match_error::MatchError,
+};
+
+/// The error of a pass that has already reported what went wrong to its sink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorsReported;
+
+#[derive(Debug)]
+pub enum ResolveError<'src> {
+  LoopControl(LoopControlError<'src>),
+  Global(GlobalError<'src>),
+  Match(MatchError<'src>),
+  Generic(GenericError<'src>),
+  Array(ArrayError<'src>),
+  Cast(CastError<'src>),
+  Condition(ConditionError<'src>),
+  Enum(EnumError<'src>),
+  Alias(AliasError<'src>),
+  Call(CallError<'src>),
+  Import(ImportError<'src>),
+}
+
+impl<'src> Reportable<'src> for ResolveError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    match self {
+      ResolveError::LoopControl(loop_control) => loop_control.report(),
+      ResolveError::Global(global) => global.report(),
+      ResolveError::Match(match_) => match_.report(),
+      ResolveError::Generic(generic) => generic.report(),
+      ResolveError::Array(array) => array.report(),
+      ResolveError::Cast(cast) => cast.report(),
+      ResolveError::Condition(condition) => condition.report(),
+      ResolveError::Enum(enumeration) => enumeration.report(),
+      ResolveError::Alias(alias) => alias.report(),
+      ResolveError::Call(call) => call.report(),
+      ResolveError::Import(import) => import.report(),
+    }
+  }
+}
@@ -4,72 +4,1065 @@ use sable_ast::{
   NodeId,
   ast::Ast,
   expression::{
+    ArrayExpression,
     AssignExpression,
     BinaryExpression,
+    BinaryOperator,
     BlockExpression,
+    CallExpression,
+    CastExpression,
+    CastKind,
     Expression,
+    ExpressionKind,
     ExpressionVisitorMut,
+    FieldExpression,
     IdentifierExpression,
+    IfExpression,
+    IndexExpression,
     LiteralExpression,
+    LoopExpression,
+    MatchExpression,
+    StructLiteralExpression,
+    UnaryExpression,
+    UnaryOperator,
+    WhileExpression,
+  },
+  objects::{
+    alias::TypeAlias,
+    enumeration::{
+      Enum,
+      EnumVariant,
+    },
+    function::Function,
+    global::{
+      Global,
+      GlobalKind,
+    },
+    import::ImportKind,
+  },
+  pattern::{
+    Pattern,
+    PatternKind,
   },
-  objects::function::Function,
   statement::{
+    BreakStatement,
+    ReturnStatement,
     Statement,
     StatementVisitorMut,
     VariableStatement,
   },
+  located::Located,
+  types::{
+    Path,
+    Type,
+  },
+};
+use indexmap::{
+  IndexMap,
+  IndexSet,
+};
+use sable_common::{
+  interner::Entry,
+  location::Location,
+  writer::{
+    Reportable,
+    Sink,
+  },
+};
+use sable_middle::{
+  context::Context,
+  scope::Symbol,
+};
+
+use crate::{
+  alias::{
+    self,
+    Aliases,
+  },
+  cast,
+  import,
+  monomorphize::Monomorphizer,
+  resolve_error::{
+    ErrorsReported,
+    ResolveError,
+    alias_error::{
+      AliasError,
+      AliasErrorKind,
+    },
+    array_error::{
+      ArrayError,
+      ArrayErrorKind,
+    },
+    call_error::{
+      CallError,
+      CallErrorKind,
+    },
+    cast_error::CastError,
+    condition_error::ConditionError,
+    enum_error::{
+      EnumError,
+      EnumErrorKind,
+    },
+    global_error::{
+      GlobalError,
+      GlobalErrorKind,
+    },
+    import_error::{
+      ImportError,
+      ImportErrorKind,
+    },
+    loop_control::{
+      LoopControlError,
+      LoopControlKind,
+    },
+    match_error::{
+      MatchError,
+      MatchErrorKind,
+    },
+  },
 };
-use sable_middle::context::Context;
 
 enum Status {
   Ok,
   Error,
 }
 
-pub struct Resolver<'ast, 'src, 'resolve> {
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LoopKind {
+  While,
+  Loop,
+}
+
+pub struct Resolver<'ast, 'src, 'resolve, D>
+where
+  D: Sink<'src> + ?Sized,
+{
   asts: &'resolve mut [&'ast mut Ast<'ast, 'src>],
   id: Cell<usize>,
   context: &'resolve mut Context<'resolve, 'src>,
+  sink: &'resolve mut D,
+  loops: Vec<LoopKind>,
+  /// Keyed by module and enum name.
+  enums: IndexMap<(Entry, Entry), &'ast [EnumVariant<'ast, 'src>]>,
+  /// The symbol and type of every global, by module and global name.
+  globals: IndexMap<(Entry, Entry), (&'resolve Symbol, Type<'src>)>,
+  /// Types of the variables bound in each enclosing scope, innermost last.
+  /// `Type::Infer` where the type cannot be told without a type checker.
+  bindings: Vec<IndexMap<Entry, Type<'src>>>,
+  /// Type parameters of the function being resolved.
+  generics: Vec<Entry>,
+  aliases: Aliases<'src>,
+  /// Types of the functions that can be used as values: those that are
+  /// neither generic nor variadic.
+  signatures: IndexMap<Entry, Type<'src>>,
+  /// Index into `asts` of every module, by module name.
+  modules: IndexMap<Entry, usize>,
+  /// The module name of each file, in the order of `asts`.
+  module_names: Vec<Entry>,
+  /// Index into `asts` of the file being resolved.
+  file: usize,
+  /// Names of the top-level items of each file, in the order of `asts`.
+  items: Vec<IndexSet<Entry>>,
+  /// Files whose items the file being resolved may refer to through a path:
+  /// those it imports and itself.
+  imported: Vec<usize>,
 }
 
-impl<'ast, 'src, 'resolve> Resolver<'ast, 'src, 'resolve> {
+impl<'ast, 'src, 'resolve, D> Resolver<'ast, 'src, 'resolve, D>
+where
+  D: Sink<'src> + ?Sized,
+{
   pub fn new(
     asts: &'resolve mut [&'ast mut Ast<'ast, 'src>],
     context: &'resolve mut Context<'resolve, 'src>,
+    sink: &'resolve mut D,
   ) -> Self {
     Resolver {
       asts,
       id: Cell::new(0),
       context,
+      sink,
+      loops: Vec::new(),
+      enums: IndexMap::new(),
+      globals: IndexMap::new(),
+      bindings: vec![IndexMap::new()],
+      generics: Vec::new(),
+      aliases: Aliases::new(),
+      signatures: IndexMap::new(),
+      modules: IndexMap::new(),
+      module_names: Vec::new(),
+      file: 0,
+      items: Vec::new(),
+      imported: Vec::new(),
     }
   }
 
+  /// The module of the file being resolved.
+  fn module(&self) -> Entry {
+    self.module_names[self.file]
+  }
+
   fn next_id(&self) -> usize {
     let id = self.id.get();
     self.id.set(id + 1);
     id
   }
 
+  fn report(&mut self, error: ResolveError<'src>) {
+    self.sink.report(error.report()).unwrap();
+  }
+
   // Used for blocks that are not part of an expression and do not carry an ID nor a Expression object.
   fn visit_block(&mut self, block: &mut BlockExpression<'ast, 'src>) -> Result<(), ()> {
+    self.bindings.push(IndexMap::new());
+    let result = self.visit_block_body(block);
+    self.bindings.pop();
+    result
+  }
+
+  fn visit_block_body(&mut self, block: &mut BlockExpression<'ast, 'src>) -> Result<(), ()> {
+    let mut status = Status::Ok;
     for stmt in block.body_mut() {
-      <Self as StatementVisitorMut>::visit_stmt_mut(self, stmt)?;
+      if <Self as StatementVisitorMut>::visit_stmt_mut(self, stmt).is_err() {
+        status = Status::Error;
+      }
+    }
+    if let Some(tail) = block.tail_mut()
+      && self.visit_expr_mut(tail).is_err()
+    {
+      status = Status::Error;
+    }
+
+    match status {
+      Status::Ok => Ok(()),
+      Status::Error => Err(()),
+    }
+  }
+
+  fn visit_loop_body(
+    &mut self,
+    kind: LoopKind,
+    body: &mut BlockExpression<'ast, 'src>,
+  ) -> Result<(), ()> {
+    self.loops.push(kind);
+    let result = self.visit_block(body);
+    self.loops.pop();
+    result
+  }
+
+  /// The type of a variable declared as `declared`, which is taken from the
+  /// initializer if omitted.
+  fn declared_type(
+    &self,
+    declared: &Type<'src>,
+    initializer: Option<&Expression<'ast, 'src>>,
+  ) -> Type<'src> {
+    match (declared, initializer) {
+      (Type::Infer, Some(initializer)) => self.type_of(initializer),
+      _ => declared.clone(),
+    }
+  }
+
+  fn bind(&mut self, name: Entry, declared: &Type<'src>, initializer: Option<&Expression<'ast, 'src>>) {
+    let ty = self.declared_type(declared, initializer);
+    self.bindings.last_mut().unwrap().insert(name, ty);
+  }
+
+  fn lookup_binding(&self, name: Entry) -> Type<'src> {
+    self
+      .bindings
+      .iter()
+      .rev()
+      .find_map(|scope| scope.get(&name))
+      .or_else(|| self.lookup_global(&[name]).map(|(_, ty)| ty))
+      .or_else(|| self.signatures.get(&name))
+      .cloned()
+      .unwrap_or_default()
+  }
+
+  /// The symbol and type of the global a path with `segments` names.
+  fn lookup_global(&self, segments: &[Entry]) -> Option<&(&'resolve Symbol, Type<'src>)> {
+    import::lookup(&self.globals, self.module(), segments).map(|(_, global)| global)
+  }
+
+  fn primitive(&self, name: &str, location: &Location<'src>) -> Type<'src> {
+    let segment = Located::builder()
+      .value(self.context.intern().intern(name))
+      .location(location.clone())
+      .build();
+    Type::Path(Path::builder().segments(vec![segment]).build())
+  }
+
+  /// The type of `expr` as far as it follows from literals, declarations and
+  /// casts alone, or `Type::Infer`.
+  fn type_of(&self, expr: &Expression<'ast, 'src>) -> Type<'src> {
+    match expr.kind() {
+      ExpressionKind::Literal(LiteralExpression::Integer(integer)) => {
+        let name = integer.suffix().map_or("i32", |suffix| suffix.name());
+        self.primitive(name, expr.location())
+      }
+      ExpressionKind::Literal(LiteralExpression::Float(float)) => {
+        let name = float.suffix().map_or("f64", |suffix| suffix.name());
+        self.primitive(name, expr.location())
+      }
+      ExpressionKind::Literal(LiteralExpression::Char(_)) => self.primitive("char", expr.location()),
+      ExpressionKind::Literal(LiteralExpression::Bool(_)) => self.primitive("bool", expr.location()),
+      ExpressionKind::Identifier(identifier) if identifier.path().is_single() => {
+        self.lookup_binding(identifier.path().name())
+      }
+      ExpressionKind::Identifier(identifier) if self.is_module_path(identifier.path()) => {
+        let name = identifier.path().name();
+        self
+          .lookup_global(&identifier.path().names())
+          .map(|(_, ty)| ty)
+          .or_else(|| self.signatures.get(&name))
+          .cloned()
+          .unwrap_or_default()
+      }
+      ExpressionKind::Cast(cast) => cast.type_().value().clone(),
+      ExpressionKind::Unary(unary) => match (unary.operator(), self.type_of(unary.operand())) {
+        (UnaryOperator::AddressOf, inner) => Type::Pointer(Box::new(inner)),
+        (UnaryOperator::Deref, operand) => match alias::expand(&operand, self.module(), &self.aliases) {
+          Type::Pointer(inner) => *inner,
+          _ => Type::Infer,
+        },
+        (UnaryOperator::Negate | UnaryOperator::Not, operand) => operand,
+      },
+      ExpressionKind::Binary(binary) => match binary.operator() {
+        BinaryOperator::Equal
+        | BinaryOperator::NotEqual
+        | BinaryOperator::Less
+        | BinaryOperator::LessEqual
+        | BinaryOperator::Greater
+        | BinaryOperator::GreaterEqual
+        | BinaryOperator::LogicalAnd
+        | BinaryOperator::LogicalOr => self.primitive("bool", expr.location()),
+        _ => match self.type_of(binary.lhs()) {
+          Type::Infer => self.type_of(binary.rhs()),
+          lhs => lhs,
+        },
+      },
+      ExpressionKind::Array(array) => {
+        let element = match array {
+          ArrayExpression::List(list) => list
+            .elements()
+            .first()
+            .map_or(Type::Infer, |first| self.type_of(first)),
+          ArrayExpression::Repeat(repeat) => self.type_of(repeat.value()),
+        };
+        match array.known_len() {
+          Some(length) => Type::Array(Box::new(element), length),
+          None => Type::Infer,
+        }
+      }
+      ExpressionKind::Index(index) => match self.resolved_type_of(index.object()) {
+        Type::Array(element, _) | Type::Slice(element) => *element,
+        _ => Type::Infer,
+      },
+      ExpressionKind::StructLiteral(struct_literal) => Type::Path(struct_literal.path().clone()),
+      ExpressionKind::Call(call) => match self.resolved_type_of(call.callee()) {
+        Type::Function(_, return_type) => *return_type,
+        _ => Type::Infer,
+      },
+      _ => Type::Infer,
+    }
+  }
+
+  fn resolved_type_of(&self, expr: &Expression<'ast, 'src>) -> Type<'src> {
+    alias::expand(&self.type_of(expr), self.module(), &self.aliases)
+  }
+
+  /// Names a type for diagnostics as written, adding what it stands for when
+  /// that differs.
+  fn describe(&self, ty: &Type<'src>) -> String {
+    let written = self.type_name(ty);
+    let resolved = self.type_name(&alias::expand(ty, self.module(), &self.aliases));
+    if written == resolved {
+      format!("`{}`", written)
+    } else {
+      format!("`{}` (aka `{}`)", written, resolved)
+    }
+  }
+
+  /// Spells out a type the way it is written in source.
+  fn type_name(&self, ty: &Type<'src>) -> String {
+    match ty {
+      Type::Infer => "_".to_string(),
+      Type::Path(path) => {
+        let mut name = path
+          .segments()
+          .iter()
+          .map(|segment| self.name(*segment.value()))
+          .collect::<Vec<_>>()
+          .join("::");
+        if !path.generics().is_empty() {
+          let args = path
+            .generics()
+            .iter()
+            .map(|arg| self.type_name(arg))
+            .collect::<Vec<_>>()
+            .join(", ");
+          name = format!("{}<{}>", name, args);
+        }
+        name
+      }
+      Type::Pointer(inner) => format!("{}*", self.type_name(inner)),
+      Type::Array(inner, length) => format!("[{}; {}]", self.type_name(inner), length),
+      Type::Slice(inner) => format!("[{}]", self.type_name(inner)),
+      Type::Function(params, return_type) => {
+        let params = params
+          .iter()
+          .map(|param| self.type_name(param))
+          .collect::<Vec<_>>()
+          .join(", ");
+        format!("func({}): {}", params, self.type_name(return_type))
+      }
+    }
+  }
+
+  fn is_primitive(&self, ty: &Type<'src>, name: &str) -> bool {
+    matches!(ty, Type::Path(path) if path.is_single()
+      && path.generics().is_empty()
+      && self.name(path.name()) == name)
+  }
+
+  /// Reports a condition whose type is known and is not `bool`.
+  fn check_condition(&mut self, condition: &Expression<'ast, 'src>) -> Result<(), ()> {
+    let ty = self.type_of(condition);
+    let resolved = alias::expand(&ty, self.module(), &self.aliases);
+    if resolved == Type::Infer
+      || self.is_primitive(&resolved, "bool")
+      || self.is_generic_param(&resolved)
+    {
+      return Ok(());
+    }
+
+    let error = ConditionError::new(condition.location().clone(), self.describe(&ty));
+    self.report(ResolveError::Condition(error));
+    Err(())
+  }
+
+  fn is_generic_param(&self, ty: &Type<'src>) -> bool {
+    matches!(ty, Type::Path(path) if path.is_single() && self.generics.contains(&path.name()))
+  }
+
+  /// Records what a cast does, or reports it if the types cannot be converted.
+  /// Casts whose source type is not known yet, or that involve a type
+  /// parameter, are left for after instantiation.
+  fn check_cast(
+    &mut self,
+    cast: &mut CastExpression<'ast, 'src>,
+    location: &Location<'src>,
+  ) -> Result<(), ()> {
+    let written_from = self.type_of(cast.value());
+    let written_to = cast.type_().value();
+    let from = alias::expand(&written_from, self.module(), &self.aliases);
+    let to = alias::expand(written_to, self.module(), &self.aliases);
+    if from == Type::Infer || self.is_generic_param(&from) || self.is_generic_param(&to) {
+      return Ok(());
+    }
+
+    let kind = match cast::classify(&from, &to, self.context.intern()) {
+      Some(kind) => kind,
+      None if self.type_name(&from) == self.type_name(&to) => CastKind::Identity,
+      None => {
+        let error = CastError::new(
+          location.clone(),
+          self.describe(&written_from),
+          self.describe(written_to),
+        );
+        self.report(ResolveError::Cast(error));
+        return Err(());
+      }
+    };
+    _ = cast.kind_mut().init(kind);
+    Ok(())
+  }
+
+  /// Reports calls of values that are not functions and calls with the wrong
+  /// number of arguments, where the callee's type is known.
+  fn check_call(&mut self, call: &CallExpression<'ast, 'src>) -> Result<(), ()> {
+    let callee = self.type_of(call.callee());
+    let kind = match alias::expand(&callee, self.module(), &self.aliases) {
+      Type::Infer => return Ok(()),
+      Type::Function(params, _) if params.len() == call.args().len() => return Ok(()),
+      Type::Function(params, _) => CallErrorKind::ArgumentCount {
+        expected: params.len(),
+        found: call.args().len(),
+      },
+      resolved if self.is_generic_param(&resolved) => return Ok(()),
+      _ => CallErrorKind::NotCallable(self.describe(&callee)),
+    };
+
+    let error = CallError::new(call.callee().location().clone(), kind);
+    self.report(ResolveError::Call(error));
+    Err(())
+  }
+
+  fn signature(&self, func: &Function<'ast, 'src>) -> Type<'src> {
+    let params = func
+      .params()
+      .iter()
+      .map(|param| param.type_().value().clone())
+      .collect();
+    Type::Function(params, Box::new(func.return_type().value().clone()))
+  }
+
+  /// Reports constant indices that fall outside an array of known length.
+  fn check_index(&mut self, index: &IndexExpression<'ast, 'src>) -> Result<(), ()> {
+    let length = match self.resolved_type_of(index.object()) {
+      Type::Array(_, length) => Some(length),
+      _ => None,
+    };
+
+    if let (Some(length), Some(value)) = (length, index.index().as_integer())
+      && (value < 0 || value as u64 >= length)
+    {
+      let kind = ArrayErrorKind::OutOfBounds {
+        length,
+        index: value,
+      };
+      let error = ArrayError::new(index.index().location().clone(), kind);
+      self.report(ResolveError::Array(error));
+      return Err(());
     }
+
     Ok(())
   }
 
-  fn resolve_func(&mut self, func: &mut Function<'ast, 'src>) -> Result<(), ()> {
-    if let Some(block) = func.block_mut() {
-      self.visit_block(block)?;
+  fn register_enum(&mut self, enumeration: &Enum<'ast, 'src>) -> Result<(), ()> {
+    let name = *enumeration.name().value();
+    if self.enums.contains_key(&(self.module(), name)) {
+      let error = EnumError::new(
+        enumeration.name().location().clone(),
+        EnumErrorKind::Redefined(self.name(name)),
+      );
+      self.report(ResolveError::Enum(error));
+      return Err(());
+    }
+
+    self.enums.insert((self.module(), name), enumeration.variants());
+    Ok(())
+  }
+
+  fn register_alias(&mut self, alias: &TypeAlias<'src>) -> Result<(), ()> {
+    let name = *alias.name().value();
+    if self.aliases.contains_key(&(self.module(), name)) {
+      let error = AliasError::new(
+        alias.name().location().clone(),
+        AliasErrorKind::Redefined(self.name(name)),
+      );
+      self.report(ResolveError::Alias(error));
+      return Err(());
     }
 
+    self.aliases.insert((self.module(), name), alias.type_().value().clone());
     Ok(())
   }
 
-  fn resolve_ast(&mut self, ast: &mut Ast<'ast, 'src>) -> Result<(), ()> {
+  /// Reports every alias that expands to itself and forgets those aliases, so
+  /// that expanding the rest always terminates.
+  fn check_alias_cycles(&mut self, asts: &[&'ast mut Ast<'ast, 'src>]) -> Result<(), ()> {
+    let mut cyclic = Vec::new();
+    for (index, ast) in asts.iter().enumerate() {
+      for alias in ast.aliases() {
+        let key = (self.module_names[index], *alias.name().value());
+        if cyclic.contains(&key) {
+          continue;
+        }
+        let Some(chain) = alias::find_cycle(key, &self.aliases) else {
+          continue;
+        };
+
+        let kind = AliasErrorKind::Cycle(chain.iter().map(|(_, name)| self.name(*name)).collect());
+        self.report(ResolveError::Alias(AliasError::new(
+          alias.name().location().clone(),
+          kind,
+        )));
+        cyclic.extend(chain);
+      }
+    }
+
+    for key in &cyclic {
+      self.aliases.shift_remove(key);
+    }
+    if cyclic.is_empty() { Ok(()) } else { Err(()) }
+  }
+
+  fn register_module(&mut self, index: usize, ast: &Ast<'ast, 'src>) {
+    let name = self.context.intern().intern(import::module_name(ast.file()));
+    self.modules.insert(name, index);
+    self.module_names.push(name);
+
+    let mut items = IndexSet::new();
+    items.extend(ast.funcs().iter().map(|func| *func.name().value()));
+    items.extend(ast.structs().iter().map(|structure| *structure.name().value()));
+    items.extend(ast.globals().iter().map(|global| *global.name().value()));
+    items.extend(ast.enums().iter().map(|enumeration| *enumeration.name().value()));
+    items.extend(ast.aliases().iter().map(|alias| *alias.name().value()));
+    self.items.push(items);
+  }
+
+  /// Finds the files each file imports, records them as its dependencies and
+  /// reports imports of files that are not being compiled and import cycles.
+  fn link_imports(&mut self, asts: &mut [&'ast mut Ast<'ast, 'src>]) -> Result<(), ()> {
     let mut status = Status::Ok;
+    let files: Vec<_> = asts
+      .iter()
+      .map(|ast| import::normalize(std::path::Path::new(ast.file())))
+      .collect();
+
+    // The file and import location each import resolves to, per file.
+    let mut resolved = Vec::with_capacity(asts.len());
+    for ast in asts.iter() {
+      let mut targets: Vec<(usize, Location<'src>)> = Vec::new();
+      for item in ast.imports() {
+        let target = *item.target().value();
+        let index = match item.kind() {
+          ImportKind::Module => self.modules.get(&target).copied(),
+          ImportKind::File => {
+            let path = import::import_path(ast.file(), self.name(target));
+            files.iter().position(|file| *file == path)
+          }
+        };
+
+        match index {
+          Some(index) if !targets.iter().any(|(seen, _)| *seen == index) => {
+            targets.push((index, item.target().location().clone()));
+          }
+          Some(_) => {}
+          None => {
+            let kind = ImportErrorKind::UnknownModule(self.name(target));
+            let error = ImportError::new(item.target().location().clone(), kind);
+            self.report(ResolveError::Import(error));
+            status = Status::Error;
+          }
+        }
+      }
+      resolved.push(targets);
+    }
+
+    for (ast, targets) in asts.iter_mut().zip(&resolved) {
+      *ast.dependencies_mut() = targets.iter().map(|(index, _)| *index).collect();
+    }
+
+    let dependencies: Vec<_> = asts.iter().map(|ast| ast.dependencies().clone()).collect();
+    let mut cyclic = Vec::new();
+    for (index, targets) in resolved.iter().enumerate() {
+      if cyclic.contains(&index) {
+        continue;
+      }
+      let Some(chain) = import::find_cycle(index, &dependencies) else {
+        continue;
+      };
+
+      let location = targets
+        .iter()
+        .find(|(target, _)| *target == chain[1])
+        .map(|(_, location)| location.clone())
+        .unwrap();
+      let kind = ImportErrorKind::Cycle(
+        chain
+          .iter()
+          .map(|member| import::module_name(asts[*member].file()))
+          .collect(),
+      );
+      self.report(ResolveError::Import(ImportError::new(location, kind)));
+      status = Status::Error;
+      cyclic.extend(chain);
+    }
+
+    match status {
+      Status::Ok => Ok(()),
+      Status::Error => Err(()),
+    }
+  }
+
+  /// Whether `path` is `module::item` for a file being compiled.
+  fn is_module_path(&self, path: &Path<'src>) -> bool {
+    path.segments().len() == 2 && self.modules.contains_key(path.segments()[0].value())
+  }
+
+  /// Checks that a path leading with a module name, such as `math::add`, goes
+  /// through an imported module to one of its items. Paths leading with an
+  /// enum name are variants and checked elsewhere.
+  fn check_module_path(&mut self, path: &Path<'src>) -> Result<(), ()> {
+    let [module, item, ..] = path.segments().as_slice() else {
+      return Ok(());
+    };
+    let module_name = *module.value();
+    if import::lookup(&self.enums, self.module(), &[module_name]).is_some() {
+      return Ok(());
+    }
+
+    let (location, kind) = match self.modules.get(&module_name) {
+      None => (
+        module.location(),
+        ImportErrorKind::UnknownModule(self.name(module_name)),
+      ),
+      Some(index) if !self.imported.contains(index) => (
+        module.location(),
+        ImportErrorKind::NotImported(self.name(module_name)),
+      ),
+      Some(index) if !self.items[*index].contains(item.value()) => (
+        item.location(),
+        ImportErrorKind::UnknownItem {
+          module: self.name(module_name),
+          item: self.name(*item.value()),
+        },
+      ),
+      Some(_) => return Ok(()),
+    };
+
+    let error = ImportError::new(location.clone(), kind);
+    self.report(ResolveError::Import(error));
+    Err(())
+  }
+
+  fn register_global(&mut self, global: &Global<'ast, 'src>) -> Result<(), ()> {
+    let name = *global.name().value();
+    if self.globals.contains_key(&(self.module(), name)) {
+      let text = self.context.intern().resolve(name).unwrap();
+      let error = GlobalError::new(
+        global.name().location().clone(),
+        GlobalErrorKind::Redefined(text),
+      );
+      self.report(ResolveError::Global(error));
+      return Err(());
+    }
+
+    let symbol = self.context.symbol_arena().alloc(Symbol::Global {
+      module: self.module(),
+      name,
+      mutable: *global.kind() == GlobalKind::Var,
+    });
+    self.context.global_scope_mut().add(symbol);
+    let ty = self.declared_type(global.type_().value(), Some(global.initializer()));
+    self.globals.insert((self.module(), name), (symbol, ty));
+    Ok(())
+  }
+
+  /// Finds the first part of a `const` initializer that cannot be evaluated at
+  /// compile time.
+  fn non_constant<'e>(
+    &self,
+    expr: &'e Expression<'ast, 'src>,
+  ) -> Option<&'e Expression<'ast, 'src>> {
+    match expr.kind() {
+      ExpressionKind::Literal(_) => None,
+      ExpressionKind::Unary(unary) => match unary.operator() {
+        UnaryOperator::Negate | UnaryOperator::Not => self.non_constant(unary.operand()),
+        UnaryOperator::AddressOf | UnaryOperator::Deref => Some(expr),
+      },
+      ExpressionKind::Binary(binary) => self
+        .non_constant(binary.lhs())
+        .or_else(|| self.non_constant(binary.rhs())),
+      ExpressionKind::StructLiteral(struct_literal) => struct_literal
+        .fields()
+        .iter()
+        .find_map(|field| self.non_constant(field.value())),
+      ExpressionKind::Array(ArrayExpression::List(list)) => list
+        .elements()
+        .iter()
+        .find_map(|element| self.non_constant(element)),
+      ExpressionKind::Array(ArrayExpression::Repeat(repeat)) => self.non_constant(repeat.value()),
+      ExpressionKind::Cast(cast) => self.non_constant(cast.value()),
+      ExpressionKind::Identifier(identifier) => match self.lookup_global(&identifier.path().names()) {
+        Some((Symbol::Global { mutable: false, .. }, _)) => None,
+        _ => Some(expr),
+      },
+      _ => Some(expr),
+    }
+  }
+
+  fn name(&self, entry: Entry) -> &'src str {
+    self.context.intern().resolve(entry).unwrap()
+  }
+
+  /// Names the enum with the given module and name for diagnostics, qualified
+  /// if it belongs to another module.
+  fn enum_name(&self, (module, name): (Entry, Entry)) -> String {
+    if module == self.module() {
+      self.name(name).to_string()
+    } else {
+      format!("{}::{}", self.name(module), self.name(name))
+    }
+  }
+
+  /// Finds the enum and variant a variant pattern path refers to. A bare
+  /// `Circle(..)` is looked up across all enums and has to be defined by
+  /// exactly one of them, or by exactly one enum of the current module.
+  fn lookup_variant(
+    &self,
+    pattern: &Pattern<'ast, 'src>,
+    segments: &[Entry],
+  ) -> Result<((Entry, Entry), &'ast EnumVariant<'ast, 'src>), MatchError<'src>> {
+    let variant = *segments.last().unwrap();
+
+    let candidates: Vec<_> = match segments {
+      [enum_path @ .., _] if !enum_path.is_empty() => {
+        match import::lookup(&self.enums, self.module(), enum_path) {
+          Some((key, variants)) => vec![(*key, *variants)],
+          None => {
+            let path = enum_path
+              .iter()
+              .map(|segment| self.name(*segment))
+              .collect::<Vec<_>>()
+              .join("::");
+            let kind = MatchErrorKind::UnknownEnum(path);
+            return Err(MatchError::new(pattern.location().clone(), kind));
+          }
+        }
+      }
+      _ => self
+        .enums
+        .iter()
+        .map(|(key, variants)| (*key, *variants))
+        .collect(),
+    };
+
+    let mut found: Vec<_> = candidates
+      .into_iter()
+      .filter_map(|(key, variants)| {
+        variants
+          .iter()
+          .find(|candidate| *candidate.name().value() == variant)
+          .map(|candidate| (key, candidate))
+      })
+      .collect();
+    if found.iter().any(|((module, _), _)| *module == self.module()) {
+      found.retain(|((module, _), _)| *module == self.module());
+    }
+
+    match found.as_slice() {
+      [found] => Ok(*found),
+      [] => {
+        let path = segments
+          .iter()
+          .map(|segment| self.name(*segment))
+          .collect::<Vec<_>>()
+          .join("::");
+        Err(MatchError::new(
+          pattern.location().clone(),
+          MatchErrorKind::UnknownVariant(path),
+        ))
+      }
+      _ => {
+        let kind = MatchErrorKind::AmbiguousVariant {
+          variant: self.name(variant).to_string(),
+          enums: found
+            .iter()
+            .map(|(key, _)| self.enum_name(*key))
+            .collect(),
+        };
+        Err(MatchError::new(pattern.location().clone(), kind))
+      }
+    }
+  }
+
+  /// Declares the names bound by `pattern` in the innermost scope. `ty` is the
+  /// type of the value the pattern is matched against.
+  fn bind_pattern(&mut self, pattern: &Pattern<'ast, 'src>, ty: Type<'src>) {
+    match pattern.kind() {
+      PatternKind::Binding(name) => self.bind(*name, &ty, None),
+      PatternKind::Variant(variant_pattern) => {
+        let segments = variant_pattern.path().names();
+        let payload = self
+          .lookup_variant(pattern, &segments)
+          .map_or(&[][..], |(_, variant)| *variant.payload());
+        for (index, field) in variant_pattern.fields().iter().enumerate() {
+          let ty = payload
+            .get(index)
+            .map_or(Type::Infer, |ty| ty.value().clone());
+          self.bind_pattern(field, ty);
+        }
+      }
+      PatternKind::Wildcard | PatternKind::Literal(_) => {}
+    }
+  }
+
+  /// Reports a binding named like a unit variant, as in `Red =>`, which
+  /// would match every value rather than only the variant.
+  fn check_binding(&mut self, pattern: &Pattern<'ast, 'src>, name: Entry) -> Result<(), ()> {
+    let Ok((enum_name, variant)) = self.lookup_variant(pattern, &[name]) else {
+      return Ok(());
+    };
+    if !variant.payload().is_empty() {
+      return Ok(());
+    }
+
+    let kind = MatchErrorKind::ShadowedVariant {
+      binding: self.name(name).to_string(),
+      variant: format!("{}::{}", self.enum_name(enum_name), self.name(name)),
+    };
+    self.report(ResolveError::Match(MatchError::new(
+      pattern.location().clone(),
+      kind,
+    )));
+    Err(())
+  }
+
+  fn check_pattern(&mut self, pattern: &Pattern<'ast, 'src>) -> Result<(), ()> {
+    let variant_pattern = match pattern.kind() {
+      PatternKind::Variant(variant_pattern) => variant_pattern,
+      PatternKind::Binding(name) => return self.check_binding(pattern, *name),
+      PatternKind::Wildcard | PatternKind::Literal(_) => return Ok(()),
+    };
+
+    let segments = variant_pattern.path().names();
+    let (enum_name, variant) = match self.lookup_variant(pattern, &segments) {
+      Ok(found) => found,
+      Err(error) => {
+        self.report(ResolveError::Match(error));
+        return Err(());
+      }
+    };
+
+    let expected = variant.payload().len();
+    let found = variant_pattern.fields().len();
+    if expected != found {
+      let kind = MatchErrorKind::Arity {
+        variant: format!(
+          "{}::{}",
+          self.enum_name(enum_name),
+          self.name(*variant.name().value())
+        ),
+        expected,
+        found,
+      };
+      self.report(ResolveError::Match(MatchError::new(
+        pattern.location().clone(),
+        kind,
+      )));
+      return Err(());
+    }
+
+    let mut status = Status::Ok;
+    for field in variant_pattern.fields().iter() {
+      if self.check_pattern(field).is_err() {
+        status = Status::Error;
+      }
+    }
+
+    match status {
+      Status::Ok => Ok(()),
+      Status::Error => Err(()),
+    }
+  }
+
+  /// Reports the variants of the matched enum that no arm covers. A variant
+  /// only counts as covered by an arm whose payload patterns are all
+  /// irrefutable.
+  fn check_exhaustive(&mut self, match_: &MatchExpression<'ast, 'src>) -> Result<(), ()> {
+    let patterns: Vec<_> = match_.arms().iter().map(|arm| arm.pattern()).collect();
+    if patterns.iter().any(|pattern| pattern.is_irrefutable()) {
+      return Ok(());
+    }
+
+    let mut covered = Vec::new();
+    let mut matched_enum = None;
+    for pattern in &patterns {
+      let PatternKind::Variant(variant_pattern) = pattern.kind() else {
+        continue;
+      };
+      let segments = variant_pattern.path().names();
+      let Ok((enum_name, variant)) = self.lookup_variant(pattern, &segments) else {
+        continue;
+      };
+
+      matched_enum.get_or_insert(enum_name);
+      if variant_pattern
+        .fields()
+        .iter()
+        .all(|field| field.is_irrefutable())
+      {
+        covered.push((enum_name, *variant.name().value()));
+      }
+    }
+
+    // `true` and `false` together cover a `bool`.
+    let bools: Vec<bool> = patterns
+      .iter()
+      .filter_map(|pattern| match pattern.kind() {
+        PatternKind::Literal(LiteralExpression::Bool(value)) => Some(*value.value()),
+        _ => None,
+      })
+      .collect();
+
+    let missing: Vec<String> = match matched_enum {
+      Some(enum_name) => self.enums[&enum_name]
+        .iter()
+        .map(|variant| *variant.name().value())
+        .filter(|variant| !covered.contains(&(enum_name, *variant)))
+        .map(|variant| format!("{}::{}", self.enum_name(enum_name), self.name(variant)))
+        .collect(),
+      None if !bools.is_empty() => [true, false]
+        .into_iter()
+        .filter(|value| !bools.contains(value))
+        .map(|value| value.to_string())
+        .collect(),
+      None => vec!["_".to_string()],
+    };
+
+    if missing.is_empty() {
+      return Ok(());
+    }
+
+    let error = MatchError::new(
+      match_.keyword().clone(),
+      MatchErrorKind::NonExhaustive(missing),
+    );
+    self.report(ResolveError::Match(error));
+    Err(())
+  }
+
+  fn resolve_global(&mut self, global: &mut Global<'ast, 'src>) -> Result<(), ()> {
+    self.visit_expr_mut(global.initializer_mut())?;
+
+    if *global.kind() == GlobalKind::Const
+      && let Some(offending) = self.non_constant(global.initializer())
+    {
+      let error = GlobalError::new(offending.location().clone(), GlobalErrorKind::NonConstant);
+      self.report(ResolveError::Global(error));
+      return Err(());
+    }
+
+    Ok(())
+  }
+
+  fn resolve_func(&mut self, func: &mut Function<'ast, 'src>) -> Result<(), ()> {
+    // Imports are defined in another object file; there is no body to resolve.
+    if func.is_import() {
+      return Ok(());
+    }
+
+    self.generics = func.generics().iter().map(|param| *param.value()).collect();
+    self.bindings.push(IndexMap::new());
+    for param in func.params().iter() {
+      self.bind(*param.name().value(), param.type_().value(), None);
+    }
+
+    let result = match func.block_mut() {
+      Some(block) => self.visit_block(block),
+      None => Ok(()),
+    };
+    self.bindings.pop();
+    result
+  }
+
+  fn resolve_ast(&mut self, index: usize, ast: &mut Ast<'ast, 'src>) -> Result<(), ()> {
+    let mut status = Status::Ok;
+    self.file = index;
+    self.imported = ast.dependencies().clone();
+    self.imported.push(index);
+
+    for global in ast.globals_mut() {
+      if self.resolve_global(global).is_err() {
+        status = Status::Error;
+      }
+    }
     for funcs in ast.funcs_mut() {
-      if let Err(_) = self.resolve_func(funcs) {
+      if self.resolve_func(funcs).is_err() {
         status = Status::Error;
       }
     }
@@ -80,13 +1073,61 @@ impl<'ast, 'src, 'resolve> Resolver<'ast, 'src, 'resolve> {
     }
   }
 
-  pub fn resolve(&mut self) -> Result<(), ()> {
+  pub fn resolve(&mut self) -> Result<(), ErrorsReported> {
     let mut status = Status::Ok;
 
     let asts = std::mem::take(&mut self.asts);
 
-    for ast in asts.iter_mut() {
-      match self.resolve_ast(ast) {
+    for (index, ast) in asts.iter().enumerate() {
+      self.register_module(index, ast);
+    }
+    if self.link_imports(asts).is_err() {
+      status = Status::Error;
+    }
+
+    for (index, ast) in asts.iter().enumerate() {
+      self.file = index;
+      for enumeration in ast.enums() {
+        if self.register_enum(enumeration).is_err() {
+          status = Status::Error;
+        }
+      }
+    }
+
+    for (index, ast) in asts.iter().enumerate() {
+      self.file = index;
+      for alias in ast.aliases() {
+        if self.register_alias(alias).is_err() {
+          status = Status::Error;
+        }
+      }
+    }
+    if self.check_alias_cycles(asts).is_err() {
+      status = Status::Error;
+    }
+
+    for ast in asts.iter() {
+      for func in ast.funcs() {
+        if !func.is_generic() && !*func.variadic() {
+          let signature = self.signature(func);
+          self.signatures.insert(*func.name().value(), signature);
+        }
+      }
+    }
+
+    // Globals are registered up front so they are visible from every function,
+    // regardless of file or declaration order.
+    for (index, ast) in asts.iter().enumerate() {
+      self.file = index;
+      for global in ast.globals() {
+        if self.register_global(global).is_err() {
+          status = Status::Error;
+        }
+      }
+    }
+
+    for (index, ast) in asts.iter_mut().enumerate() {
+      match self.resolve_ast(index, ast) {
         Ok(_) => {}
         Err(_) => {
           status = Status::Error;
@@ -98,12 +1139,69 @@ impl<'ast, 'src, 'resolve> Resolver<'ast, 'src, 'resolve> {
 
     match status {
       Status::Ok => Ok(()),
-      Status::Error => Err(()),
+      Status::Error => Err(ErrorsReported),
+    }
+  }
+
+  /// Specializes the generic items with a [`Monomorphizer`] and resolves the
+  /// instances it adds. An instance body is checked against its concrete
+  /// types, so `x as bool` in `conv<f64>` is reported even though the cast
+  /// was accepted in the generic `conv<T>`.
+  pub fn monomorphize(&mut self) -> Result<(), ErrorsReported> {
+    let asts = std::mem::take(&mut self.asts);
+    let resolved: Vec<_> = asts
+      .iter()
+      .map(|ast| (ast.funcs().len(), ast.structs().len()))
+      .collect();
+
+    let intern = *self.context.intern();
+    let result = Monomorphizer::new(asts, intern, self.sink).monomorphize();
+    if result.is_err() {
+      self.asts = asts;
+      return result;
+    }
+
+    // Instances are items of the module of their generic item, which paths
+    // such as `math::N4math3maxE$3i32` rewritten from `math::max<i32>` refer to.
+    for (index, (ast, (funcs, structs))) in asts.iter().zip(&resolved).enumerate() {
+      let items = &mut self.items[index];
+      items.extend(ast.funcs()[*funcs..].iter().map(|func| *func.name().value()));
+      items.extend(ast.structs()[*structs..].iter().map(|structure| *structure.name().value()));
+    }
+    for (ast, (funcs, _)) in asts.iter().zip(&resolved) {
+      for func in &ast.funcs()[*funcs..] {
+        if !*func.variadic() {
+          let signature = self.signature(func);
+          self.signatures.insert(*func.name().value(), signature);
+        }
+      }
+    }
+
+    let mut status = Status::Ok;
+    for (index, (ast, (funcs, _))) in asts.iter_mut().zip(&resolved).enumerate() {
+      self.file = index;
+      self.imported = ast.dependencies().clone();
+      self.imported.push(index);
+      for func in &mut ast.funcs_mut()[*funcs..] {
+        if self.resolve_func(func).is_err() {
+          status = Status::Error;
+        }
+      }
+    }
+
+    self.asts = asts;
+
+    match status {
+      Status::Ok => Ok(()),
+      Status::Error => Err(ErrorsReported),
     }
   }
 }
 
-impl<'ast, 'src, 'resolve> ExpressionVisitorMut<'ast, 'src> for Resolver<'ast, 'src, 'resolve> {
+impl<'ast, 'src, 'resolve, D> ExpressionVisitorMut<'ast, 'src> for Resolver<'ast, 'src, 'resolve, D>
+where
+  D: Sink<'src> + ?Sized,
+{
   type VisitReturn = Result<(), ()>;
 
   fn visit_block_mut(
@@ -113,15 +1211,12 @@ impl<'ast, 'src, 'resolve> ExpressionVisitorMut<'ast, 'src> for Resolver<'ast, '
   ) -> Self::VisitReturn {
     let id = self.next_id();
     _ = expr.id_mut().init(NodeId(id));
-    for stmt in block.body_mut() {
-      self.visit_stmt_mut(stmt)?;
-    }
-    Ok(())
+    self.visit_block(block)
   }
 
   fn visit_literal_mut(
     &mut self,
-    literal: &mut LiteralExpression,
+    _literal: &mut LiteralExpression,
     expr: &mut Expression<'ast, 'src>,
   ) -> Self::VisitReturn {
     let id = self.next_id();
@@ -136,6 +1231,7 @@ impl<'ast, 'src, 'resolve> ExpressionVisitorMut<'ast, 'src> for Resolver<'ast, '
   ) -> Self::VisitReturn {
     let id = self.next_id();
     _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(assign.target_mut())?;
     self.visit_expr_mut(assign.value_mut())?;
     Ok(())
   }
@@ -153,18 +1249,191 @@ impl<'ast, 'src, 'resolve> ExpressionVisitorMut<'ast, 'src> for Resolver<'ast, '
     Ok(())
   }
 
-  fn visit_identifier_mut(
+  fn visit_unary_mut(
+    &mut self,
+    unary: &mut UnaryExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(unary.operand_mut())?;
+    Ok(())
+  }
+
+  fn visit_call_mut(
+    &mut self,
+    call: &mut CallExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(call.callee_mut())?;
+    for arg in call.args_mut().iter_mut() {
+      self.visit_expr_mut(arg)?;
+    }
+    self.check_call(call)
+  }
+
+  fn visit_if_mut(
+    &mut self,
+    if_: &mut IfExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(if_.condition_mut())?;
+    self.check_condition(if_.condition())?;
+    self.visit_block(if_.then_block_mut())?;
+    if let Some(else_branch) = if_.else_branch_mut() {
+      self.visit_expr_mut(else_branch)?;
+    }
+    Ok(())
+  }
+
+  fn visit_while_mut(
+    &mut self,
+    while_: &mut WhileExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(while_.condition_mut())?;
+    self.check_condition(while_.condition())?;
+    self.visit_loop_body(LoopKind::While, while_.body_mut())
+  }
+
+  fn visit_loop_mut(
+    &mut self,
+    loop_: &mut LoopExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_loop_body(LoopKind::Loop, loop_.body_mut())
+  }
+
+  fn visit_match_mut(
+    &mut self,
+    match_: &mut MatchExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(match_.scrutinee_mut())?;
+    let scrutinee = self.type_of(match_.scrutinee());
+
+    let mut status = Status::Ok;
+    for arm in match_.arms_mut().iter_mut() {
+      if self.check_pattern(arm.pattern()).is_err() {
+        status = Status::Error;
+      }
+
+      self.bindings.push(IndexMap::new());
+      self.bind_pattern(arm.pattern(), scrutinee.clone());
+      if self.visit_expr_mut(arm.body_mut()).is_err() {
+        status = Status::Error;
+      }
+      self.bindings.pop();
+    }
+
+    match status {
+      Status::Ok => self.check_exhaustive(match_),
+      Status::Error => Err(()),
+    }
+  }
+
+  fn visit_struct_literal_mut(
+    &mut self,
+    struct_literal: &mut StructLiteralExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.check_module_path(struct_literal.path())?;
+    for field in struct_literal.fields_mut().iter_mut() {
+      self.visit_expr_mut(field.value_mut())?;
+    }
+    Ok(())
+  }
+
+  fn visit_field_mut(
+    &mut self,
+    field: &mut FieldExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(field.object_mut())
+  }
+
+  fn visit_array_mut(
     &mut self,
-    identifier: &mut IdentifierExpression,
+    array: &mut ArrayExpression<'ast, 'src>,
     expr: &mut Expression<'ast, 'src>,
   ) -> Self::VisitReturn {
     let id = self.next_id();
     _ = expr.id_mut().init(NodeId(id));
+    match array {
+      ArrayExpression::List(list) => {
+        for element in list.elements_mut().iter_mut() {
+          self.visit_expr_mut(element)?;
+        }
+      }
+      ArrayExpression::Repeat(repeat) => {
+        self.visit_expr_mut(repeat.value_mut())?;
+        self.visit_expr_mut(repeat.count_mut())?;
+
+        if let Some(offending) = self.non_constant(repeat.count()) {
+          let error = ArrayError::new(
+            offending.location().clone(),
+            ArrayErrorKind::NonConstantCount,
+          );
+          self.report(ResolveError::Array(error));
+          return Err(());
+        }
+      }
+    }
     Ok(())
   }
+
+  fn visit_index_mut(
+    &mut self,
+    index: &mut IndexExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(index.object_mut())?;
+    self.visit_expr_mut(index.index_mut())?;
+    self.check_index(index)
+  }
+
+  fn visit_cast_mut(
+    &mut self,
+    cast: &mut CastExpression<'ast, 'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.visit_expr_mut(cast.value_mut())?;
+    self.check_cast(cast, expr.location())
+  }
+
+  fn visit_identifier_mut(
+    &mut self,
+    identifier: &mut IdentifierExpression<'src>,
+    expr: &mut Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = expr.id_mut().init(NodeId(id));
+    self.check_module_path(identifier.path())
+  }
 }
 
-impl<'ast, 'src, 'resolve> StatementVisitorMut<'ast, 'src> for Resolver<'ast, 'src, 'resolve> {
+impl<'ast, 'src, 'resolve, D> StatementVisitorMut<'ast, 'src> for Resolver<'ast, 'src, 'resolve, D>
+where
+  D: Sink<'src> + ?Sized,
+{
   type VisitReturn = Result<(), ()>;
 
   fn visit_expression_mut(
@@ -186,6 +1455,455 @@ impl<'ast, 'src, 'resolve> StatementVisitorMut<'ast, 'src> for Resolver<'ast, 's
     let id = self.next_id();
     _ = statement.id_mut().init(NodeId(id));
     self.visit_expr_mut(variable.initializer_mut())?;
+
+    self.bind(
+      *variable.name().value(),
+      variable.type_().value(),
+      Some(variable.initializer()),
+    );
+    Ok(())
+  }
+
+  fn visit_return_mut(
+    &mut self,
+    return_: &mut ReturnStatement<'ast, 'src>,
+    statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = statement.id_mut().init(NodeId(id));
+    if let Some(value) = return_.value_mut() {
+      self.visit_expr_mut(value)?;
+    }
+    Ok(())
+  }
+
+  fn visit_break_mut(
+    &mut self,
+    break_: &mut BreakStatement<'ast, 'src>,
+    statement: &mut Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = statement.id_mut().init(NodeId(id));
+
+    let kind = match (self.loops.last(), break_.value()) {
+      (None, _) => Some(LoopControlKind::Break),
+      (Some(LoopKind::While), Some(_)) => Some(LoopControlKind::BreakWithValue),
+      _ => None,
+    };
+    if let Some(kind) = kind {
+      let error = LoopControlError::new(statement.location().clone(), kind);
+      self.report(ResolveError::LoopControl(error));
+      return Err(());
+    }
+
+    if let Some(value) = break_.value_mut() {
+      self.visit_expr_mut(value)?;
+    }
+    Ok(())
+  }
+
+  fn visit_continue_mut(&mut self, statement: &mut Statement<'ast, 'src>) -> Self::VisitReturn {
+    let id = self.next_id();
+    _ = statement.id_mut().init(NodeId(id));
+
+    if self.loops.is_empty() {
+      let error = LoopControlError::new(statement.location().clone(), LoopControlKind::Continue);
+      self.report(ResolveError::LoopControl(error));
+      return Err(());
+    }
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::testing::{
+    render,
+    reports,
+    resolve,
+  };
+
+  /// Resolves a file that has to resolve cleanly.
+  fn accepts(content: &str) {
+    resolve(&[("main.sable", content)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+    });
+  }
+
+  #[test]
+  fn test_loop_control() {
+    accepts("func f(): i32 { while true { break; continue; } loop { break 1; } 0 }");
+
+    let found = reports("func f(): i32 { break; 0 }");
+    assert!(found.contains("`break` outside of a loop"));
+
+    let found = reports("func f(): i32 { if true { continue; } 0 }");
+    assert!(found.contains("`continue` outside of a loop"));
+
+    let found = reports("func f(): i32 { while true { break 1; } 0 }");
+    assert!(found.contains("`break` with a value inside a `while` loop"));
+  }
+
+  #[test]
+  fn test_qualified_paths() {
+    let math = ("math.sable", "func max(a: i32, b: i32): i32 { a }");
+    resolve(
+      &[math, ("main.sable", "import math; func f(): i32 { math::max(1, 2) }")],
+      |resolved| assert!(resolved.ok, "{:#?}", resolved.reports.0),
+    );
+    resolve(
+      &[math, ("main.sable", "func f(): i32 { math::max(1, 2) }")],
+      |resolved| assert!(resolved.reports.contains("Module `math` is not imported")),
+    );
+    resolve(
+      &[math, ("main.sable", "import math; func f(): i32 { math::min(1, 2) }")],
+      |resolved| assert!(resolved.reports.contains("Module `math` has no item `min`")),
+    );
+  }
+
+  #[test]
+  fn test_constant_initializers() {
+    accepts("const A: u8 = 1 as u8; const B: i32 = -(A as i32) * 2; const C: i32 = B;");
+
+    let found = reports("var V: i32 = 1; const C: i32 = V;");
+    assert!(found.contains("Constant initializer is not a compile-time constant"));
+
+    let found = reports("func f(): i32 { 1 } const C: i32 = f();");
+    assert!(found.contains("Constant initializer is not a compile-time constant"));
+
+    let limits = ("limits.sable", "const MAX: i32 = 8; var count: i32 = 0;");
+    resolve(
+      &[limits, ("main.sable", "import limits; const C: i32 = limits::MAX + 1;")],
+      |resolved| assert!(resolved.ok, "{:#?}", resolved.reports.0),
+    );
+    resolve(
+      &[limits, ("main.sable", "import limits; const C: i32 = limits::count;")],
+      |resolved| {
+        assert!(resolved.reports.contains("Constant initializer is not a compile-time constant"))
+      },
+    );
+  }
+
+  #[test]
+  fn test_global_redefined() {
+    let found = reports("const A: i32 = 1; var A: i32 = 2;");
+    assert!(found.contains("Global `A` is defined more than once"));
+  }
+
+  #[test]
+  fn test_variadic_calls() {
+    let printf = "extern \"C\" func printf(format: u8*, ...): i32;";
+    accepts(&format!("{} func f(): i32 {{ printf(\"%d %d\", 1, 2) }}", printf));
+    accepts(&format!("{} func f(): i32 {{ printf(\"done\") }}", printf));
+
+    // Only fixed-arity externs have their argument count checked.
+    let found = reports("extern \"C\" func exit(code: i32): i32; func f(): i32 { exit(1, 2) }");
+    assert!(found.contains("Wrong number of arguments"));
+  }
+
+  const SHAPE: &str = "enum Shape { Circle(f64), Rect(f64, f64), Empty }";
+
+  #[test]
+  fn test_match_exhaustive() {
+    accepts(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Circle(_) => 1, Rect(w, h) => 2, Shape::Empty => 3 }} }}",
+      SHAPE
+    ));
+    accepts(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Circle(r) => 1, _ => 2 }} }}",
+      SHAPE
+    ));
+    accepts("func f(b: bool): i32 { match b { true => 1, false => 0 } }");
+    accepts("func f(n: i32): i32 { match n { 0 => 1, other => 2 } }");
+  }
+
+  #[test]
+  fn test_match_non_exhaustive() {
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Circle(_) => 1, Shape::Empty => 3 }} }}",
+      SHAPE
+    ));
+    assert!(found.contains("Non-exhaustive match"));
+
+    // A refutable payload pattern does not cover its variant.
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Circle(1.0) => 1, Rect(_, _) => 2, Shape::Empty => 3 }} }}",
+      SHAPE
+    ));
+    assert!(found.contains("Non-exhaustive match"));
+
+    let found = reports("func f(b: bool): i32 { match b { true => 1 } }");
+    assert!(found.contains("Non-exhaustive match"));
+
+    let found = reports("func f(n: i32): i32 { match n { 0 => 1, 1 => 2 } }");
+    assert!(found.contains("Non-exhaustive match"));
+  }
+
+  #[test]
+  fn test_match_patterns() {
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Square(_) => 1, _ => 2 }} }}",
+      SHAPE
+    ));
+    assert!(found.contains("Unknown variant `Shape::Square`"));
+
+    let found = reports("func f(s: i32): i32 { match s { Color::Red => 1, _ => 2 } }");
+    assert!(found.contains("Unknown enum `Color`"));
+
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Rect(w) => 1, _ => 2 }} }}",
+      SHAPE
+    ));
+    assert!(found.contains("Wrong number of fields for `Shape::Rect`"));
+
+    let color = "enum Color { Red, Green }";
+    let found = reports(&format!(
+      "{} func f(c: Color): i32 {{ match c {{ Red => 1, _ => 2 }} }}",
+      color
+    ));
+    assert!(found.contains("Binding `Red` shadows the variant `Color::Red`"));
+
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Circle(Empty) => 1, _ => 2 }} }}",
+      SHAPE
+    ));
+    assert!(found.contains("Binding `Empty` shadows the variant `Shape::Empty`"));
+
+    // A binding named like a variant with a payload cannot be mistaken for it.
+    accepts(&format!(
+      "{} {} func f(c: Color): i32 {{ match c {{ Color::Red => 1, Circle => 2 }} }}",
+      SHAPE, color
+    ));
+  }
+
+  #[test]
+  fn test_ambiguous_variant() {
+    let enums = "enum Shape { Circle(f64), Empty } enum Token { Circle(i32), Word(i32) }";
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Circle(_) => 1, _ => 2 }} }}",
+      enums
+    ));
+    assert!(found.contains("Ambiguous variant `Circle`"));
+
+    accepts(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Shape::Circle(_) => 1, Shape::Empty => 2 }} }}",
+      enums
+    ));
+    accepts(&format!(
+      "{} func f(t: Token): i32 {{ match t {{ Word(_) => 1, _ => 2 }} }}",
+      enums
+    ));
+  }
+
+  #[test]
+  fn test_enum_redefined() {
+    let found = reports("enum Shape { Circle(f64) } enum Shape { Empty }");
+    assert!(found.contains("Enum `Shape` is defined more than once"));
+  }
+
+  #[test]
+  fn test_match_bindings() {
+    // The payload binding has the variant's payload type.
+    let found = reports(&format!(
+      "{} func f(s: Shape): i32 {{ match s {{ Circle(r) => if r {{ 1 }} else {{ 2 }}, _ => 0 }} }}",
+      SHAPE
+    ));
+    assert!(found.contains("Condition is not a `bool`"));
+
+    // A binding shadows a function of the same name, and only in its arm.
+    let found = reports("func g(): i32 { 0 } func f(n: i32): i32 { match n { g => g(), } }");
+    assert!(found.contains("Call of a value that is not a function"));
+    accepts("func g(): i32 { 0 } func f(n: i32): i32 { match n { 0 => 1, g => 2 }; g() }");
+  }
+
+  #[test]
+  fn test_array_index() {
+    accepts("func f(): i32 { var a: [i32; 3] = [1, 2, 3]; a[2] }");
+    accepts("func f(i: i64): i32 { var a: [i32; 3] = [0; 3]; a[i] }");
+
+    let found = reports("func f(): i32 { var a: [i32; 3] = [1, 2, 3]; a[3] }");
+    assert!(found.contains("Index out of bounds"));
+
+    let found = reports("func f(): i32 { var a: [i32; 3] = [1, 2, 3]; a[-1] }");
+    assert!(found.contains("Index out of bounds"));
+  }
+
+  #[test]
+  fn test_array_repeat_count() {
+    accepts("const N: i32 = 4; func f(): i32 { var a: [i32; 4] = [0; N]; a[0] }");
+
+    let found = reports("func f(n: i32): i32 { var a: [i32; 4] = [0; n]; a[0] }");
+    assert!(found.contains("Array repeat count is not a compile-time constant"));
+  }
+
+  #[test]
+  fn test_casts() {
+    accepts("func f(p: i8*, x: i64): u64 { var q: u8* = p as u8*; (x as i32) as u64 + q as u64 }");
+
+    let found = reports("func f(x: f64): bool { x as bool }");
+    assert!(found.contains("Invalid cast from `f64` to `bool`"));
+
+    let found = reports("struct P { x: i32 } func f(p: P): i32 { p as i32 }");
+    assert!(found.contains("Invalid cast from `P` to `i32`"));
+  }
+
+  #[test]
+  fn test_bool_conditions() {
+    accepts("func f(b: bool, x: i32): i32 { if b { 1 } else if !b && x < 2 { 2 } else { 3 } }");
+    accepts("func f(x: i32): i32 { while x != 0 || false { break; } 0 }");
+    accepts("type Flag = bool; func f(b: Flag): i32 { if b { 1 } else { 0 } }");
+
+    let found = reports("func f(x: i32): i32 { if x { 1 } else { 0 } }");
+    assert!(found.contains("Condition is not a `bool`"));
+
+    let found = reports("func f(p: i8*): i32 { while p { break; } 0 }");
+    assert!(found.contains("Condition is not a `bool`"));
+
+    let found = reports("func f(): i32 { if 1 { 1 } else { 0 } }");
+    assert!(found.contains("Condition is not a `bool`"));
+  }
+
+  #[test]
+  fn test_alias_transparency() {
+    accepts("type Argv = i8**; func f(argv: Argv): i8 { **argv }");
+    accepts("type Meters = f64; func f(x: Meters): i64 { x as i64 }");
+
+    let found = reports("type Meters = f64; func f(x: Meters): bool { x as bool }");
+    assert!(found.contains("Invalid cast from `Meters` (aka `f64`) to `bool`"));
+  }
+
+  #[test]
+  fn test_alias_errors() {
+    let found = reports("type A = i32; type A = u8;");
+    assert!(found.contains("Type alias `A` is defined more than once"));
+
+    let found = reports("type A = [A; 2];");
+    assert!(found.contains("Type alias `A` refers to itself"));
+
+    // One report per cycle, however many aliases it passes through.
+    let found = reports("type A = B*; type B = C; type C = A; type D = A;");
+    assert_eq!(found.0.len(), 1, "{:#?}", found.0);
+    assert!(found.contains("Type alias `A` refers to itself"));
+  }
+
+  #[test]
+  fn test_function_values() {
+    accepts(
+      "func add(a: i32, b: i32): i32 { a + b }
+       func apply(f: func(i32, i32): i32): i32 { f(1, 2) }
+       func g(): i32 { var h: func(i32, i32): i32 = add; apply(add) + h(3, 4) }",
+    );
+    accepts("struct S { cb: func(i32): i32 } func f(s: S): i32 { s.cb(1) }");
+    accepts(
+      "type Cmp = func(i8*, i8*): i32;
+       extern \"C\" func qsort(base: i8*, n: usize, size: usize, cmp: Cmp): i32;
+       func by_byte(a: i8*, b: i8*): i32 { 0 }
+       func f(p: i8*): i32 { qsort(p, 4, 1, by_byte) }",
+    );
+  }
+
+  #[test]
+  fn test_indirect_call_errors() {
+    let found = reports("func f(cb: func(i32): i32): i32 { cb(1, 2) }");
+    assert!(found.contains("Wrong number of arguments"));
+
+    let found = reports("type Cb = func(): i32; func f(cb: Cb): i32 { cb(1) }");
+    assert!(found.contains("Wrong number of arguments"));
+
+    let found = reports("func f(x: i32): i32 { x(1) }");
+    assert!(found.contains("Call of a value that is not a function"));
+  }
+
+  #[test]
+  fn test_imports() {
+    let math = ("lib/math.sable", "func add(a: i32, b: i32): i32 { a + b }");
+    resolve(
+      &[math, ("main.sable", "import math; func f(): i32 { math::add(1, 2) }")],
+      |resolved| assert!(resolved.ok, "{:#?}", resolved.reports.0),
+    );
+    resolve(
+      &[math, ("main.sable", "import \"lib/math.sable\"; func f(): i32 { math::add(1, 2) }")],
+      |resolved| assert!(resolved.ok, "{:#?}", resolved.reports.0),
+    );
+
+    let found = reports("import missing; func f(): i32 { 0 }");
+    assert!(found.contains("Unknown module `missing`"));
+
+    resolve(
+      &[math, ("main.sable", "import \"math.sable\"; func f(): i32 { 0 }")],
+      |resolved| assert!(resolved.reports.contains("Unknown module `math.sable`")),
+    );
+  }
+
+  #[test]
+  fn test_module_scoped_items() {
+    let a = ("a.sable", "type Id = i32; enum E { Red, Green } const X: i32 = 1; var Y: i32 = 0;");
+    let b = ("b.sable", "type Id = f64; enum E { Red, Blue } const Y: f64 = 2.0;");
+    let main = "import a; import b;
+      func f(p: a::Id, q: b::Id): i64 { (p as i64) + (q as i64) }
+      func g(e: b::E): i32 { match e { b::E::Red => 1, b::E::Blue => 2 } }
+      const Z: f64 = b::Y;";
+    resolve(&[a, b, ("main.sable", main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+    });
+
+    let main = "import a; import b; func f(q: b::Id): bool { q as bool }";
+    resolve(&[a, b, ("main.sable", main)], |resolved| {
+      assert!(resolved.reports.contains("Invalid cast from `b::Id` (aka `f64`) to `bool`"));
+    });
+
+    let main = "import a; import b; func g(e: a::E): i32 { match e { a::E::Red => 1 } }";
+    resolve(&[a, b, ("main.sable", main)], |resolved| {
+      assert!(resolved.reports.contains("Non-exhaustive match"));
+    });
+
+    // A bare variant or name prefers the items of its own module.
+    let main = "import a; import b; enum E { Red(i32), Gray } type Id = bool;
+      func g(e: E): i32 { match e { Red(_) => 1, E::Gray => 2 } }
+      func h(x: Id): Id { x }";
+    resolve(&[a, b, ("main.sable", main)], |resolved| {
+      assert!(resolved.ok, "{:#?}", resolved.reports.0);
+    });
+
+    let main = "import a; import b; const Z: i32 = a::Y;";
+    resolve(&[a, b, ("main.sable", main)], |resolved| {
+      assert!(resolved.reports.contains("Constant initializer is not a compile-time constant"));
+    });
+  }
+
+  #[test]
+  fn test_import_cycles() {
+    let found = reports("import main; func f(): i32 { 0 }");
+    assert!(found.contains("Module `main` imports itself"));
+
+    let files = [
+      ("a.sable", "import b; func f(): i32 { 0 }"),
+      ("b.sable", "import c; func g(): i32 { 0 }"),
+      ("c.sable", "import a; func h(): i32 { 0 }"),
+      ("d.sable", "import a; func k(): i32 { 0 }"),
+    ];
+    resolve(&files, |resolved| {
+      assert!(!resolved.ok);
+      // Reported once, from its first member; `d` only leads into it.
+      assert_eq!(resolved.reports.0.len(), 1, "{:#?}", resolved.reports.0);
+      assert!(resolved.reports.contains("Module `a` imports itself"));
+    });
+  }
+
+  #[test]
+  fn test_report_on_non_ascii_line() {
+    // Ranges are byte offsets, and `größe` takes more bytes than letters.
+    let rendered = render("func f(größe: f64): bool { größe as bool }");
+    assert!(rendered.contains("main.sable:1:28"), "{}", rendered);
+
+    let lines: Vec<_> = rendered.lines().collect();
+    let source = lines
+      .iter()
+      .position(|line| line.contains("größe as bool }"))
+      .unwrap();
+    let column = |line: &str, pattern: char| line.chars().position(|c| c == pattern);
+    let cast = lines[source].find("größe as bool").unwrap();
+    let cast = lines[source][..cast].chars().count();
+    assert_eq!(column(lines[source + 1], '─'), Some(cast), "{}", rendered);
+  }
+}
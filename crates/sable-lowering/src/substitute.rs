@@ -0,0 +1,496 @@
+use sable_arena::TypedArena;
+use sable_ast::{
+  ast::Ast,
+  expression::{
+    ArrayExpression,
+    AssignExpression,
+    BinaryExpression,
+    BlockExpression,
+    CallExpression,
+    CastExpression,
+    Expression,
+    ExpressionKind,
+    ExpressionVisitor,
+    FieldExpression,
+    IdentifierExpression,
+    IfExpression,
+    IndexExpression,
+    LiteralExpression,
+    LoopExpression,
+    MatchExpression,
+    StructLiteralExpression,
+    UnaryExpression,
+    WhileExpression,
+    array_expression::{
+      ArrayListExpression,
+      ArrayRepeatExpression,
+    },
+    match_expression::MatchArm,
+    struct_literal_expression::FieldInitializer,
+  },
+  located::Located,
+  pattern::{
+    Pattern,
+    PatternKind,
+    VariantPattern,
+  },
+  statement::{
+    BreakStatement,
+    ReturnStatement,
+    Statement,
+    StatementKind,
+    StatementVisitor,
+    VariableStatement,
+  },
+  types::{
+    Path,
+    Type,
+  },
+};
+use sable_common::{
+  interner::Entry,
+  once::Once,
+};
+
+/// Replaces the type parameters named in `substitution` inside `ty`.
+pub fn substitute<'src>(ty: &Type<'src>, substitution: &[(Entry, Type<'src>)]) -> Type<'src> {
+  match ty {
+    Type::Infer => Type::Infer,
+    Type::Pointer(inner) => Type::Pointer(Box::new(substitute(inner, substitution))),
+    Type::Array(inner, len) => Type::Array(Box::new(substitute(inner, substitution)), *len),
+    Type::Slice(inner) => Type::Slice(Box::new(substitute(inner, substitution))),
+    Type::Function(params, return_type) => Type::Function(
+      params
+        .iter()
+        .map(|param| substitute(param, substitution))
+        .collect(),
+      Box::new(substitute(return_type, substitution)),
+    ),
+    Type::Path(path) => {
+      if path.is_single() && path.generics().is_empty() {
+        let name = path.name();
+        if let Some((_, concrete)) = substitution.iter().find(|(param, _)| *param == name) {
+          return concrete.clone();
+        }
+      }
+      Type::Path(substitute_path(path, substitution))
+    }
+  }
+}
+
+/// Replaces the type parameters in the type arguments of `path`.
+pub fn substitute_path<'src>(
+  path: &Path<'src>,
+  substitution: &[(Entry, Type<'src>)],
+) -> Path<'src> {
+  let mut path = path.clone();
+  let generics = path
+    .generics()
+    .iter()
+    .map(|arg| substitute(arg, substitution))
+    .collect();
+  path.set_generics(generics);
+  path
+}
+
+/// Deep-copies the body of a generic item into the arenas of its `Ast` with
+/// its type parameters replaced, which is how an instance such as `max<i32>`
+/// gets a body of its own. The copies carry no node IDs until the resolver
+/// resolves the instance.
+pub struct Substituter<'sub, 'ast, 'src> {
+  arena: &'ast TypedArena<Expression<'ast, 'src>>,
+  arm_arena: &'ast TypedArena<MatchArm<'ast, 'src>>,
+  pattern_arena: &'ast TypedArena<Pattern<'ast, 'src>>,
+  field_init_arena: &'ast TypedArena<FieldInitializer<'ast, 'src>>,
+  substitution: &'sub [(Entry, Type<'src>)],
+}
+
+impl<'sub, 'ast, 'src> Substituter<'sub, 'ast, 'src> {
+  pub fn new(ast: &Ast<'ast, 'src>, substitution: &'sub [(Entry, Type<'src>)]) -> Self {
+    Substituter {
+      arena: ast.expr_arena(),
+      arm_arena: ast.arm_arena(),
+      pattern_arena: ast.pattern_arena(),
+      field_init_arena: ast.field_init_arena(),
+      substitution,
+    }
+  }
+
+  pub fn type_(&self, ty: &Located<'src, Type<'src>>) -> Located<'src, Type<'src>> {
+    ty.replace(substitute(ty.value(), self.substitution))
+  }
+
+  pub fn copy(&mut self, expr: &Expression<'ast, 'src>) -> Expression<'ast, 'src> {
+    let kind = self.visit_expr(expr);
+    Expression::builder()
+      .location(expr.location().clone())
+      .kind(kind)
+      .build()
+  }
+
+  fn copy_boxed(&mut self, expr: &Expression<'ast, 'src>) -> &'ast mut Expression<'ast, 'src> {
+    let copy = self.copy(expr);
+    self.arena.alloc(copy)
+  }
+
+  // Copies are collected before the slice is allocated, as copying allocates
+  // nested expressions in the same arena.
+  fn copy_slice(&mut self, exprs: &[Expression<'ast, 'src>]) -> &'ast mut [Expression<'ast, 'src>] {
+    let copies: Vec<_> = exprs.iter().map(|expr| self.copy(expr)).collect();
+    let mut copies = copies.into_iter();
+    self
+      .arena
+      .alloc_slice_with(exprs.len(), |_| copies.next().unwrap())
+  }
+
+  pub fn copy_block(&mut self, block: &BlockExpression<'ast, 'src>) -> BlockExpression<'ast, 'src> {
+    let body = block
+      .body()
+      .iter()
+      .map(|statement| self.copy_statement(statement))
+      .collect();
+    let tail = block.tail().as_ref().map(|tail| self.copy_boxed(tail));
+    BlockExpression::builder().body(body).tail(tail).build()
+  }
+
+  fn copy_statement(&mut self, statement: &Statement<'ast, 'src>) -> Statement<'ast, 'src> {
+    let kind = self.visit_stmt(statement);
+    Statement::builder()
+      .location(statement.location().clone())
+      .kind(kind)
+      .build()
+  }
+
+  fn copy_pattern(&mut self, pattern: &Pattern<'ast, 'src>) -> Pattern<'ast, 'src> {
+    let kind = match pattern.kind() {
+      PatternKind::Wildcard => PatternKind::Wildcard,
+      PatternKind::Binding(name) => PatternKind::Binding(*name),
+      PatternKind::Literal(literal) => PatternKind::Literal(literal.clone()),
+      PatternKind::Variant(variant) => {
+        let fields: Vec<_> = variant
+          .fields()
+          .iter()
+          .map(|field| self.copy_pattern(field))
+          .collect();
+        let mut fields = fields.into_iter();
+        let fields = self
+          .pattern_arena
+          .alloc_slice_with(variant.fields().len(), |_| fields.next().unwrap());
+        PatternKind::Variant(
+          VariantPattern::builder()
+            .path(variant.path().clone())
+            .fields(fields)
+            .build(),
+        )
+      }
+    };
+    Pattern::builder()
+      .kind(kind)
+      .location(pattern.location().clone())
+      .build()
+  }
+}
+
+impl<'sub, 'ast, 'src> ExpressionVisitor<'ast, 'src> for Substituter<'sub, 'ast, 'src> {
+  type VisitReturn = ExpressionKind<'ast, 'src>;
+
+  fn visit_block(
+    &mut self,
+    block: &BlockExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Block(self.copy_block(block))
+  }
+
+  fn visit_literal(
+    &mut self,
+    literal: &LiteralExpression,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Literal(literal.clone())
+  }
+
+  fn visit_assign(
+    &mut self,
+    assign: &AssignExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Assign(
+      AssignExpression::builder()
+        .operator(*assign.operator())
+        .target(self.copy_boxed(assign.target()))
+        .value(self.copy_boxed(assign.value()))
+        .build(),
+    )
+  }
+
+  fn visit_binary(
+    &mut self,
+    binary: &BinaryExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let lhs = self.copy_boxed(binary.lhs());
+    let rhs = self.copy_boxed(binary.rhs());
+    ExpressionKind::Binary(BinaryExpression::new(binary.operator(), lhs, rhs))
+  }
+
+  fn visit_unary(
+    &mut self,
+    unary: &UnaryExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Unary(
+      UnaryExpression::builder()
+        .operator(*unary.operator())
+        .operand(self.copy_boxed(unary.operand()))
+        .build(),
+    )
+  }
+
+  fn visit_call(
+    &mut self,
+    call: &CallExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Call(
+      CallExpression::builder()
+        .callee(self.copy_boxed(call.callee()))
+        .args(self.copy_slice(call.args()))
+        .build(),
+    )
+  }
+
+  fn visit_if(
+    &mut self,
+    if_: &IfExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::If(
+      IfExpression::builder()
+        .condition(self.copy_boxed(if_.condition()))
+        .then_block(self.copy_block(if_.then_block()))
+        .else_branch(
+          if_
+            .else_branch()
+            .as_ref()
+            .map(|else_branch| self.copy_boxed(else_branch)),
+        )
+        .build(),
+    )
+  }
+
+  fn visit_while(
+    &mut self,
+    while_: &WhileExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::While(
+      WhileExpression::builder()
+        .condition(self.copy_boxed(while_.condition()))
+        .body(self.copy_block(while_.body()))
+        .build(),
+    )
+  }
+
+  fn visit_loop(
+    &mut self,
+    loop_: &LoopExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Loop(
+      LoopExpression::builder()
+        .body(self.copy_block(loop_.body()))
+        .build(),
+    )
+  }
+
+  fn visit_match(
+    &mut self,
+    match_: &MatchExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let scrutinee = self.copy_boxed(match_.scrutinee());
+    let arms: Vec<_> = match_
+      .arms()
+      .iter()
+      .map(|arm| {
+        MatchArm::builder()
+          .pattern(self.copy_pattern(arm.pattern()))
+          .body(self.copy(arm.body()))
+          .build()
+      })
+      .collect();
+    let mut arms = arms.into_iter();
+    let arms = self
+      .arm_arena
+      .alloc_slice_with(match_.arms().len(), |_| arms.next().unwrap());
+
+    ExpressionKind::Match(
+      MatchExpression::builder()
+        .keyword(match_.keyword().clone())
+        .scrutinee(scrutinee)
+        .arms(arms)
+        .build(),
+    )
+  }
+
+  fn visit_struct_literal(
+    &mut self,
+    struct_literal: &StructLiteralExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let fields: Vec<_> = struct_literal
+      .fields()
+      .iter()
+      .map(|field| {
+        FieldInitializer::builder()
+          .name(field.name().clone())
+          .value(self.copy(field.value()))
+          .build()
+      })
+      .collect();
+    let mut fields = fields.into_iter();
+    let fields = self
+      .field_init_arena
+      .alloc_slice_with(struct_literal.fields().len(), |_| fields.next().unwrap());
+
+    ExpressionKind::StructLiteral(
+      StructLiteralExpression::builder()
+        .path(substitute_path(struct_literal.path(), self.substitution))
+        .fields(fields)
+        .build(),
+    )
+  }
+
+  fn visit_field(
+    &mut self,
+    field: &FieldExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Field(
+      FieldExpression::builder()
+        .object(self.copy_boxed(field.object()))
+        .field(field.field().clone())
+        .build(),
+    )
+  }
+
+  fn visit_array(
+    &mut self,
+    array: &ArrayExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    let array = match array {
+      ArrayExpression::List(list) => ArrayExpression::List(
+        ArrayListExpression::builder()
+          .elements(self.copy_slice(list.elements()))
+          .build(),
+      ),
+      ArrayExpression::Repeat(repeat) => ArrayExpression::Repeat(
+        ArrayRepeatExpression::builder()
+          .value(self.copy_boxed(repeat.value()))
+          .count(self.copy_boxed(repeat.count()))
+          .build(),
+      ),
+    };
+    ExpressionKind::Array(array)
+  }
+
+  fn visit_index(
+    &mut self,
+    index: &IndexExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Index(
+      IndexExpression::builder()
+        .object(self.copy_boxed(index.object()))
+        .index(self.copy_boxed(index.index()))
+        .build(),
+    )
+  }
+
+  fn visit_cast(
+    &mut self,
+    cast: &CastExpression<'ast, 'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    // A kind the resolver could decide does not depend on type parameters.
+    let kind = match cast.kind().get() {
+      Some(kind) => Once::new_init(*kind),
+      None => Once::new_uninit(),
+    };
+    ExpressionKind::Cast(
+      CastExpression::builder()
+        .value(self.copy_boxed(cast.value()))
+        .type_(self.type_(cast.type_()))
+        .kind(kind)
+        .build(),
+    )
+  }
+
+  fn visit_identifier(
+    &mut self,
+    identifier: &IdentifierExpression<'src>,
+    _expr: &Expression<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    ExpressionKind::Identifier(
+      IdentifierExpression::builder()
+        .path(substitute_path(identifier.path(), self.substitution))
+        .build(),
+    )
+  }
+}
+
+impl<'sub, 'ast, 'src> StatementVisitor<'ast, 'src> for Substituter<'sub, 'ast, 'src> {
+  type VisitReturn = StatementKind<'ast, 'src>;
+
+  fn visit_expression(
+    &mut self,
+    expr: &Expression<'ast, 'src>,
+    _statement: &Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    StatementKind::Expression(self.copy(expr))
+  }
+
+  fn visit_variable(
+    &mut self,
+    variable: &VariableStatement<'ast, 'src>,
+    _statement: &Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    StatementKind::Variable(
+      VariableStatement::builder()
+        .name(variable.name().clone())
+        .initializer(self.copy(variable.initializer()))
+        .type_(self.type_(variable.type_()))
+        .build(),
+    )
+  }
+
+  fn visit_return(
+    &mut self,
+    return_: &ReturnStatement<'ast, 'src>,
+    _statement: &Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    StatementKind::Return(
+      ReturnStatement::builder()
+        .value(return_.value().as_ref().map(|value| self.copy(value)))
+        .build(),
+    )
+  }
+
+  fn visit_break(
+    &mut self,
+    break_: &BreakStatement<'ast, 'src>,
+    _statement: &Statement<'ast, 'src>,
+  ) -> Self::VisitReturn {
+    StatementKind::Break(
+      BreakStatement::builder()
+        .value(break_.value().as_ref().map(|value| self.copy(value)))
+        .build(),
+    )
+  }
+
+  fn visit_continue(&mut self, _statement: &Statement<'ast, 'src>) -> Self::VisitReturn {
+    StatementKind::Continue
+  }
+}
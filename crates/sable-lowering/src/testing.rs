@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use ariadne::Report;
+use heaped::arena::DroplessArena;
+use sable_arena::TypedArena;
+use sable_ast::ast::{
+  Arenas,
+  Ast,
+};
+use sable_common::{
+  cache::ErrorCache,
+  file::{
+    Span,
+    source::Source,
+  },
+  interner::{
+    Entry,
+    StrInterner,
+  },
+  writer::{
+    ReportWriter,
+    Sink,
+  },
+};
+use sable_middle::context::Context;
+use sable_parse::{
+  lexer::Lexer,
+  parser::Parser,
+};
+
+use crate::resolver::Resolver;
+
+/// Keeps reports instead of printing them. Reports only expose their message
+/// through `Debug`, so that is what is kept.
+#[derive(Default)]
+pub struct Reports(pub Vec<String>);
+
+impl Reports {
+  pub fn contains(&self, message: &str) -> bool {
+    self.0.iter().any(|report| report.contains(message))
+  }
+}
+
+impl<'src> Sink<'src> for Reports {
+  type Error = ();
+
+  fn report(&mut self, report: Report<'_, Span<'src>>) -> Result<(), Self::Error> {
+    self.0.push(format!("{:?}", report));
+    Ok(())
+  }
+}
+
+pub struct Resolved<'a, 'ast, 'src> {
+  pub asts: &'a mut [&'ast mut Ast<'ast, 'src>],
+  pub intern: &'a StrInterner<'src>,
+  pub reports: Reports,
+  pub ok: bool,
+}
+
+impl<'a, 'ast, 'src> Resolved<'a, 'ast, 'src> {
+  pub fn name(&self, entry: Entry) -> &'src str {
+    self.intern.resolve(entry).unwrap()
+  }
+}
+
+/// Parses `files` as `(filename, content)` pairs and resolves them together.
+/// Every file has to parse.
+pub fn resolve<R>(files: &[(&str, &str)], check: impl FnOnce(&mut Resolved<'_, '_, '_>) -> R) -> R {
+  compile(files, false, check)
+}
+
+/// Parses and resolves `files` and, if `monomorphize`, specializes their
+/// generic items, which then have to have resolved.
+fn compile<R>(
+  files: &[(&str, &str)],
+  monomorphize: bool,
+  check: impl FnOnce(&mut Resolved<'_, '_, '_>) -> R,
+) -> R {
+  let strings = DroplessArena::new(4096);
+  let intern = StrInterner::new(&strings);
+  let sources = TypedArena::new();
+  let arenas = Arenas::default();
+  let asts_arena = TypedArena::new();
+
+  let mut reports = Reports::default();
+  let mut asts = Vec::new();
+  for (filename, content) in files {
+    let source = Arc::new_in(Source::new(content, filename, &sources), &sources);
+    let ast = asts_arena.alloc(Ast::new(source.filename(), &arenas));
+    let parsed = Parser::new(Lexer::new(source.clone()), ast, &mut reports, &intern).parse();
+    assert!(parsed.is_ok(), "{:#?}", reports.0);
+    asts.push(ast);
+  }
+
+  let scopes = TypedArena::new();
+  let symbols = TypedArena::new();
+  let mut context = Context::new(&intern, &scopes, &symbols);
+  let mut resolver = Resolver::new(&mut asts, &mut context, &mut reports);
+  let resolved = resolver.resolve().is_ok();
+  let ok = resolved && (!monomorphize || resolver.monomorphize().is_ok());
+  if monomorphize {
+    assert!(resolved, "{:#?}", reports.0);
+  }
+
+  check(&mut Resolved {
+    asts: &mut asts,
+    intern: &intern,
+    reports,
+    ok,
+  })
+}
+
+/// Resolves a single file and returns what was reported.
+pub fn reports(content: &str) -> Reports {
+  resolve(&[("main.sable", content)], |resolved| {
+    std::mem::take(&mut resolved.reports)
+  })
+}
+
+/// Resolves `files`, which have to resolve, and monomorphizes them.
+pub fn monomorphize<R>(
+  files: &[(&str, &str)],
+  check: impl FnOnce(&mut Resolved<'_, '_, '_>) -> R,
+) -> R {
+  compile(files, true, check)
+}
+
+/// Resolves a single file and returns its reports as the compiler prints
+/// them, without colors.
+pub fn render(content: &str) -> String {
+  let strings = DroplessArena::new(4096);
+  let intern = StrInterner::new(&strings);
+  let sources = TypedArena::new();
+  let arenas = Arenas::default();
+
+  let source = Arc::new_in(Source::new(content, "main.sable", &sources), &sources);
+  let mut cache = ErrorCache::new();
+  cache.add_file(&source);
+  let mut out = Vec::new();
+  let mut writer = ReportWriter::new(&mut cache, &mut out);
+
+  let mut ast = Ast::new(source.filename(), &arenas);
+  let parsed = Parser::new(Lexer::new(source.clone()), &mut ast, &mut writer, &intern).parse();
+  if parsed.is_ok() {
+    let scopes = TypedArena::new();
+    let symbols = TypedArena::new();
+    let mut context = Context::new(&intern, &scopes, &symbols);
+    let mut asts = [&mut ast];
+    _ = Resolver::new(&mut asts, &mut context, &mut writer).resolve();
+  }
+
+  let rendered = String::from_utf8(out).unwrap();
+  strip_colors(&rendered)
+}
+
+/// Drops the ANSI escape sequences that color a rendered report.
+fn strip_colors(rendered: &str) -> String {
+  let mut plain = String::with_capacity(rendered.len());
+  let mut chars = rendered.chars();
+  while let Some(c) = chars.next() {
+    if c == '\u{1b}' {
+      chars.by_ref().find(|c| c.is_ascii_alphabetic());
+    } else {
+      plain.push(c);
+    }
+  }
+  plain
+}
@@ -9,13 +9,17 @@ use crate::scope::{
   Symbol,
 };
 
+pub const GLOBAL_SCOPE: ScopeId = ScopeId(0);
+
 #[derive(Debug, Getters)]
 pub struct Context<'ast, 'src> {
   #[getset(get = "pub")]
   intern: &'ast StrInterner<'src>,
   #[getset(get = "pub")]
   scopes: IndexMap<ScopeId, &'ast mut Scope<'ast>>,
+  #[getset(get = "pub")]
   scope_arena: &'ast TypedArena<Scope<'ast>>,
+  #[getset(get = "pub")]
   symbol_arena: &'ast TypedArena<Symbol>,
 }
 
@@ -27,7 +31,7 @@ impl<'ast, 'src> Context<'ast, 'src> {
   ) -> Self {
     let mut scopes = IndexMap::new();
     let global = scope_arena.alloc(Scope::new(ScopeId(scopes.len()), None));
-    scopes.insert(global.id().clone(), global);
+    scopes.insert(*global.id(), global);
 
     Context {
       intern,
@@ -36,4 +40,12 @@ impl<'ast, 'src> Context<'ast, 'src> {
       symbol_arena,
     }
   }
+
+  pub fn global_scope(&self) -> &Scope<'ast> {
+    self.scopes[&GLOBAL_SCOPE]
+  }
+
+  pub fn global_scope_mut(&mut self) -> &mut Scope<'ast> {
+    self.scopes.get_mut(&GLOBAL_SCOPE).unwrap()
+  }
 }
@@ -1,8 +1,24 @@
 use getset::Getters;
 use indexmap::IndexSet;
+use sable_common::interner::Entry;
 
 #[derive(Debug, Eq, Hash, PartialEq)]
-pub enum Symbol {}
+pub enum Symbol {
+  /// A file-scope `const` or `var` of `module`, visible from every function.
+  Global {
+    module: Entry,
+    name: Entry,
+    mutable: bool,
+  },
+}
+
+impl Symbol {
+  pub fn name(&self) -> Entry {
+    match self {
+      Symbol::Global { name, .. } => *name,
+    }
+  }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ScopeId(pub usize);
@@ -33,6 +49,13 @@ impl<'scope> Scope<'scope> {
     self.symbols.insert(symbol);
   }
 
+  pub fn lookup(&self, name: Entry) -> Option<&'scope Symbol> {
+    match self.symbols.iter().find(|symbol| symbol.name() == name) {
+      Some(symbol) => Some(symbol),
+      None => self.parent.and_then(|parent| parent.lookup(name)),
+    }
+  }
+
   pub fn symbol(&self, id: SymbolId) -> Option<&'scope Symbol> {
     if let Some(symbol) = self.symbols.get_index(id.0) {
       Some(symbol)
@@ -6,6 +6,7 @@ use ariadne::{
 use sable_common::{
   file::Span,
   location::Location,
+  writer::build_report,
 };
 
 #[derive(Debug)]
@@ -21,7 +22,7 @@ impl<'src> CommentError<'src> {
 
   pub fn report(&self) -> Report<'_, Span<'src>> {
     let span = (*self.location.filename(), self.location.range().clone());
-    Report::build(ReportKind::Error, span.clone())
+    build_report(ReportKind::Error, span.clone())
       .with_message("Unterminated block comment")
       .with_label(Label::new(span).with_message("Comment not terminated"))
       .finish()
@@ -0,0 +1,33 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::build_report,
+};
+
+/// A run of `///` or `//!` comments with no item after it to document, such
+/// as one inside a block or at the end of a file. Its text is dropped.
+#[derive(Debug)]
+pub struct DocWarning<'src> {
+  pub location: Location<'src>,
+}
+
+impl<'src> DocWarning<'src> {
+  pub fn new(location: Location<'src>) -> Self {
+    Self { location }
+  }
+
+  pub fn report(&self) -> Report<'_, Span<'src>> {
+    let span = (*self.location.filename(), self.location.range().clone());
+
+    build_report(ReportKind::Warning, span.clone())
+      .with_message("Doc comment does not document anything")
+      .with_label(Label::new(span).with_message("No item follows this comment"))
+      .with_help("Move it before an item, or use `//` for a plain comment")
+      .finish()
+  }
+}
@@ -0,0 +1,40 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::build_report,
+};
+
+#[derive(Debug)]
+pub struct EscapeError<'src> {
+  pub lexeme: &'src str,
+  pub location: Location<'src>,
+  pub literal: Location<'src>,
+}
+
+impl<'src> EscapeError<'src> {
+  pub fn new(lexeme: &'src str, location: Location<'src>, literal: Location<'src>) -> Self {
+    Self {
+      lexeme,
+      location,
+      literal,
+    }
+  }
+
+  pub fn report(&self) -> Report<'_, Span<'src>> {
+    let span = (*self.location.filename(), self.location.range().clone());
+    let literal_span = (*self.literal.filename(), self.literal.range().clone());
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(format!("Invalid escape sequence: `{}`", self.lexeme))
+      .with_label(Label::new(span).with_message(
+        "Expected one of `\\n`, `\\t`, `\\r`, `\\0`, `\\\\`, `\\\"`, `\\'`, `\\x00`-`\\x7F` or `\\u{...}`",
+      ))
+      .with_label(Label::new(literal_span).with_message("In this literal"))
+      .finish()
+  }
+}
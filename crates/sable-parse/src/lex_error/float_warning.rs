@@ -0,0 +1,67 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_ast::token::FloatWarningKind;
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::build_report,
+};
+
+/// A float literal that is valid but does not have the value it spells out.
+#[derive(Debug)]
+pub struct FloatWarning<'src> {
+  pub lexeme: &'src str,
+  pub location: Location<'src>,
+  pub kind: FloatWarningKind,
+  /// The type the literal is rounded to.
+  pub type_name: &'static str,
+  pub value: f64,
+}
+
+impl<'src> FloatWarning<'src> {
+  pub fn new(
+    lexeme: &'src str,
+    location: Location<'src>,
+    kind: FloatWarningKind,
+    type_name: &'static str,
+    value: f64,
+  ) -> Self {
+    Self {
+      lexeme,
+      location,
+      kind,
+      type_name,
+      value,
+    }
+  }
+
+  pub fn report(&self) -> Report<'_, Span<'src>> {
+    let span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      FloatWarningKind::Inexact => (
+        format!(
+          "Float literal `{}` has more precision than `{}` holds",
+          self.lexeme, self.type_name
+        ),
+        format!("Rounds to {}", self.value),
+      ),
+      FloatWarningKind::Overflow => (
+        format!("Float literal `{}` is too large for `{}`", self.lexeme, self.type_name),
+        "Rounds to infinity".to_string(),
+      ),
+      FloatWarningKind::Underflow => (
+        format!("Float literal `{}` is too small for `{}`", self.lexeme, self.type_name),
+        "Rounds to zero".to_string(),
+      ),
+    };
+
+    build_report(ReportKind::Warning, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
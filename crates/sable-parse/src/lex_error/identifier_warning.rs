@@ -0,0 +1,64 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::build_report,
+};
+
+#[derive(Debug)]
+pub enum IdentifierWarningKind<'src> {
+  /// Letters from more than one script, like a Latin name with a Cyrillic
+  /// `а` in it.
+  MixedScript,
+  /// Looks like another identifier in the same file.
+  Confusable {
+    other: &'src str,
+    other_location: Location<'src>,
+  },
+}
+
+/// An identifier that is valid but may not be the name it appears to be.
+#[derive(Debug)]
+pub struct IdentifierWarning<'src> {
+  pub name: &'src str,
+  pub location: Location<'src>,
+  pub kind: IdentifierWarningKind<'src>,
+}
+
+impl<'src> IdentifierWarning<'src> {
+  pub fn new(name: &'src str, location: Location<'src>, kind: IdentifierWarningKind<'src>) -> Self {
+    Self {
+      name,
+      location,
+      kind,
+    }
+  }
+
+  pub fn report(&self) -> Report<'_, Span<'src>> {
+    let span = (*self.location.filename(), self.location.range().clone());
+
+    let report = build_report(ReportKind::Warning, span.clone());
+    match &self.kind {
+      IdentifierWarningKind::MixedScript => report
+        .with_message(format!("Identifier `{}` mixes scripts", self.name))
+        .with_label(Label::new(span).with_message("Some of these letters only look like the others"))
+        .with_help("Spell the name in a single script"),
+      IdentifierWarningKind::Confusable {
+        other,
+        other_location,
+      } => {
+        let other_span = (*other_location.filename(), other_location.range().clone());
+        report
+          .with_message(format!("Identifier `{}` looks like `{}`", self.name, other))
+          .with_label(Label::new(span).with_message("This name"))
+          .with_label(Label::new(other_span).with_message("Can be mistaken for this one"))
+          .with_help("Rename one of them so they can be told apart")
+      }
+    }
+    .finish()
+  }
+}
@@ -1,3 +1,8 @@
 pub mod comment_error;
+pub mod doc_warning;
+pub mod escape_error;
+pub mod float_warning;
+pub mod identifier_warning;
 pub mod numeric_error;
+pub mod string_error;
 pub mod unknown_char;
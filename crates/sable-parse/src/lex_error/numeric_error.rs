@@ -1,30 +1,121 @@
 use ariadne::{
   Label,
-  Report,
   ReportKind,
 };
+use sable_ast::token::IntegerSuffix;
 use sable_common::{
   file::Span,
   location::Location,
+  writer::build_report,
 };
 
+#[derive(Debug)]
+pub enum NumericErrorKind<'src> {
+  MissingDigits,
+  MissingExponent,
+  MissingBinaryExponent,
+  /// The offending digit and where it is.
+  InvalidDigit {
+    digit: &'src str,
+    radix: u32,
+    location: Location<'src>,
+  },
+  /// The offending suffix, where it is and whether it follows a float.
+  UnknownSuffix {
+    suffix: &'src str,
+    location: Location<'src>,
+    float: bool,
+  },
+  TooLarge,
+  OutOfRange(IntegerSuffix),
+}
+
 #[derive(Debug)]
 pub struct NumericError<'src> {
   pub lexeme: &'src str,
   pub location: Location<'src>,
+  pub kind: NumericErrorKind<'src>,
 }
 
 impl<'src> NumericError<'src> {
-  pub fn new(lexeme: &'src str, location: Location<'src>) -> Self {
-    Self { lexeme, location }
+  pub fn new(lexeme: &'src str, location: Location<'src>, kind: NumericErrorKind<'src>) -> Self {
+    Self {
+      lexeme,
+      location,
+      kind,
+    }
   }
 
   pub fn report(&self) -> ariadne::Report<'_, Span<'src>> {
-    let span = (*self.location.filename(), self.location.range().clone());
+    let location = match &self.kind {
+      NumericErrorKind::InvalidDigit { location, .. }
+      | NumericErrorKind::UnknownSuffix { location, .. } => location,
+      _ => &self.location,
+    };
+    let span = (*location.filename(), location.range().clone());
+
+    let (message, label) = match &self.kind {
+      NumericErrorKind::MissingDigits => (
+        format!("Number literal `{}` has no digits", self.lexeme),
+        "Expected digits after the prefix".to_string(),
+      ),
+      NumericErrorKind::MissingExponent => (
+        format!("Float literal `{}` has no exponent digits", self.lexeme),
+        "Expected digits after the exponent marker".to_string(),
+      ),
+      NumericErrorKind::MissingBinaryExponent => (
+        format!("Hexadecimal float `{}` has no exponent", self.lexeme),
+        "Hexadecimal floats need a `p` exponent, as in `0x1.8p0`".to_string(),
+      ),
+      NumericErrorKind::InvalidDigit { digit, radix, .. } => {
+        let (name, label) = match radix {
+          2 => ("binary", "Binary literals only use the digits 0 and 1"),
+          8 => ("octal", "Octal literals only use the digits 0 to 7"),
+          16 => ("hexadecimal", "Hexadecimal literals only use 0 to 9 and a to f"),
+          _ => ("decimal", "Decimal literals only use the digits 0 to 9"),
+        };
+        (
+          format!("Invalid digit `{}` in {} literal", digit, name),
+          label.to_string(),
+        )
+      }
+      NumericErrorKind::UnknownSuffix {
+        suffix,
+        float: true,
+        ..
+      } => (
+        format!("Unknown suffix `{}` on float literal", suffix),
+        "Expected `f32` or `f64`".to_string(),
+      ),
+      NumericErrorKind::UnknownSuffix { suffix, .. } => (
+        format!("Unknown suffix `{}` on integer literal", suffix),
+        "Expected one of `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64` or `usize`"
+          .to_string(),
+      ),
+      NumericErrorKind::TooLarge => (
+        format!("Integer literal `{}` is too large", self.lexeme),
+        "Integer literals must fit in 128 bits".to_string(),
+      ),
+      NumericErrorKind::OutOfRange(suffix) => {
+        let largest = if suffix.is_signed() {
+          suffix.max() - 1
+        } else {
+          suffix.max()
+        };
+        (
+          format!(
+            "Integer literal `{}` does not fit in `{}`",
+            self.lexeme,
+            suffix.name()
+          ),
+          format!("The largest `{}` is {}", suffix.name(), largest),
+        )
+      }
+    };
 
-    Report::build(ReportKind::Error, span.clone())
-      .with_message(format!("Invalid number: `{}`", self.lexeme))
-      .with_label(Label::new(span).with_message("This number literal is invalid."))
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
       .finish()
   }
 }
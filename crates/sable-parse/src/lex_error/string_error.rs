@@ -0,0 +1,58 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::build_report,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum StringErrorKind {
+  UnterminatedString,
+  UnterminatedChar,
+  InvalidChar,
+}
+
+#[derive(Debug)]
+pub struct StringError<'src> {
+  pub lexeme: &'src str,
+  pub location: Location<'src>,
+  pub kind: StringErrorKind,
+}
+
+impl<'src> StringError<'src> {
+  pub fn new(lexeme: &'src str, location: Location<'src>, kind: StringErrorKind) -> Self {
+    Self {
+      lexeme,
+      location,
+      kind,
+    }
+  }
+
+  pub fn report(&self) -> Report<'_, Span<'src>> {
+    let span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      StringErrorKind::UnterminatedString => (
+        "Unterminated string literal",
+        "String not terminated before end of line",
+      ),
+      StringErrorKind::UnterminatedChar => (
+        "Unterminated character literal",
+        "Character not terminated before end of line",
+      ),
+      StringErrorKind::InvalidChar => (
+        "Invalid character literal",
+        "Character literals must contain exactly one character",
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
@@ -1,11 +1,11 @@
 use ariadne::{
   Label,
-  Report,
   ReportKind,
 };
 use sable_common::{
   file::Span,
   location::Location,
+  writer::build_report,
 };
 
 #[derive(Debug)]
@@ -22,7 +22,7 @@ impl<'src> UnknownCharError<'src> {
   pub fn report(&self) -> ariadne::Report<'_, Span<'src>> {
     let span = (*self.location.filename(), self.location.range().clone());
 
-    Report::build(ReportKind::Error, span.clone())
+    build_report(ReportKind::Error, span.clone())
       .with_message(format!("Unknown character: `{}`", self.lexeme))
       .with_label(Label::new(span).with_message("This character is not recognized."))
       .finish()
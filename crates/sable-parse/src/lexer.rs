@@ -1,7 +1,15 @@
-use std::sync::Arc;
+use std::{
+  ops::Range,
+  sync::Arc,
+};
 
 use sable_arena::TypedArena;
 use sable_ast::token::{
+  FloatError,
+  FloatSuffix,
+  FloatWarningKind,
+  IntegerError,
+  IntegerSuffix,
   Token,
   TokenData,
   TokenError,
@@ -11,12 +19,41 @@ use sable_common::{
   file::source::Source,
   location::Location,
 };
+use unicode_xid::UnicodeXID;
 
 const KEYWORDS: phf::Map<&'static str, TokenKind> = phf::phf_map! {
   "func" => TokenKind::Func,
   "var" =>  TokenKind::Var,
+  "return" => TokenKind::Return,
+  "if" => TokenKind::If,
+  "else" => TokenKind::Else,
+  "while" => TokenKind::While,
+  "loop" => TokenKind::Loop,
+  "break" => TokenKind::Break,
+  "continue" => TokenKind::Continue,
+  "struct" => TokenKind::Struct,
+  "const" => TokenKind::Const,
+  "extern" => TokenKind::Extern,
+  "enum" => TokenKind::Enum,
+  "match" => TokenKind::Match,
+  "as" => TokenKind::As,
+  "true" => TokenKind::True,
+  "false" => TokenKind::False,
+  "type" => TokenKind::Type,
+  "import" => TokenKind::Import,
+  "_" => TokenKind::Underscore,
 };
 
+/// A saved lexer position, used by the parser to backtrack after parsing
+/// ahead speculatively.
+pub struct Checkpoint<'src> {
+  pos: usize,
+  start: usize,
+  next: Option<Token<'src>>,
+  docs: Vec<Token<'src>>,
+  stray_docs: usize,
+}
+
 pub struct Lexer<'src> {
   source: Arc<Source<'src>, &'src TypedArena<Source<'src>>>,
 
@@ -24,6 +61,14 @@ pub struct Lexer<'src> {
   start: usize,
 
   next: Option<Token<'src>>,
+  /// The doc comments directly before `next`.
+  docs: Vec<Token<'src>>,
+  /// Where runs of doc comments were skipped without being taken, as nothing
+  /// they could document followed them.
+  stray_docs: Vec<Location<'src>>,
+  /// Whether whitespace and comments are returned as tokens, so that the
+  /// lexemes add up to the whole source.
+  trivia: bool,
 }
 
 impl<'src> Lexer<'src> {
@@ -35,6 +80,19 @@ impl<'src> Lexer<'src> {
       start: 0,
 
       next: None,
+      docs: Vec::new(),
+      stray_docs: Vec::new(),
+      trivia: false,
+    }
+  }
+
+  /// A lexer that also returns whitespace, newlines and comments, including
+  /// doc comments, as tokens. For tools that need every byte of the source,
+  /// like formatters; the parser expects them skipped.
+  pub fn lossless(source: Arc<Source<'src>, &'src TypedArena<Source<'src>>>) -> Self {
+    Self {
+      trivia: true,
+      ..Self::new(source)
     }
   }
 
@@ -73,51 +131,100 @@ impl<'src> Lexer<'src> {
     false
   }
 
+  /// Skips whitespace and comments, stopping early at doc comments and
+  /// unterminated comments to return them. With `trivia` set, it returns the
+  /// first whitespace or comment as a token instead of skipping it.
   fn skip_trivial(&mut self) -> Option<Token<'src>> {
     loop {
-      match self.get_char(0) {
-        Some(' ' | '\t' | '\r' | '\n') => self.advance(),
+      let trivia_start = self.pos;
+      let kind = match self.get_char(0) {
+        Some('\n') => {
+          self.advance();
+          TokenKind::Newline
+        }
+        Some('\r') if self.check(1, |c| c == '\n') => {
+          self.advance();
+          self.advance();
+          TokenKind::Newline
+        }
+        Some(' ' | '\t' | '\r') => {
+          // A `\r` right before `\n` belongs to the newline.
+          while self.check(0, |c| c == ' ' || c == '\t')
+            || (self.check(0, |c| c == '\r') && !self.check(1, |c| c == '\n'))
+          {
+            self.advance();
+          }
+          TokenKind::Whitespace
+        }
         Some('/') if self.check(1, |c| c == '/') => {
+          // `///` and `//!` are doc comments, but `////` is not.
+          let kind = match (self.get_char(2), self.get_char(3)) {
+            (Some('/'), next) if next != Some('/') => Some(TokenKind::DocComment),
+            (Some('!'), _) => Some(TokenKind::InnerDocComment),
+            _ => None,
+          };
+
           while let Some(c) = self.get_char(0) {
-            if c != '\n' {
-              self.advance();
-            } else {
+            if c == '\n' || (c == '\r' && self.check(1, |c| c == '\n')) {
               break;
             }
+            self.advance();
           }
+          if let Some(kind) = kind {
+            self.start = trivia_start;
+            return Some(self.make_token(kind, None));
+          }
+          TokenKind::LineComment
         }
         Some('/') if self.check(1, |c| c == '*') => {
           let comment_start = self.pos;
           self.advance(); // skip '/'
           self.advance(); // skip '*'
-          let mut terminated = false;
+          // Block comments nest, so that commenting out code that contains
+          // one does not end early.
+          let mut depth = 1;
           while let Some(c) = self.get_char(0) {
             if c == '*' && self.check(1, |c| c == '/') {
               self.advance(); // skip '*'
               self.advance(); // skip '/'
-              terminated = true;
-              break;
+              depth -= 1;
+              if depth == 0 {
+                break;
+              }
+            } else if c == '/' && self.check(1, |c| c == '*') {
+              self.advance(); // skip '/'
+              self.advance(); // skip '*'
+              depth += 1;
             } else {
               self.advance();
             }
           }
-          if !terminated {
+          if depth > 0 {
             self.start = comment_start;
             return Some(self.make_token(
               TokenKind::Error,
               Some(TokenData::Error(TokenError::UnterminatedComment)),
             ));
           }
+          TokenKind::BlockComment
         }
         _ => break,
+      };
+
+      if self.trivia {
+        self.start = trivia_start;
+        return Some(self.make_token(kind, None));
       }
     }
     None
   }
 
+  /// Lexes an identifier as defined by UAX #31: `_` or an `XID_Start`
+  /// character, which has been consumed, followed by `XID_Continue`
+  /// characters. The parser normalizes it before interning.
   fn lex_identifier(&mut self) -> Token<'src> {
     while self.get_char(0).is_some() {
-      if self.check(0, |c| c.is_ascii_alphanumeric() || c == '_') {
+      if self.check(0, |c| c.is_xid_continue()) {
         self.advance();
       } else {
         break;
@@ -132,44 +239,369 @@ impl<'src> Lexer<'src> {
     self.make_token(TokenKind::Identifier, None)
   }
 
+  /// Lexes an integer or float literal whose first digit has been consumed.
+  /// Integers may have a `0x`, `0o` or `0b` prefix, `_` separators and a
+  /// type suffix such as `u8`. Floats may have an exponent, an `f32` or `f64`
+  /// suffix, and be hexadecimal with a binary exponent, like `0x1.8p3`.
   fn lex_number(&mut self) -> Token<'src> {
-    let lex_num = |lexer: &mut Self| {
-      while let Some(c) = lexer.get_char(0) {
-        if c.is_ascii_digit() {
-          lexer.advance();
-        } else {
-          break;
-        }
+    let radix = match (&self.source.content()[self.start..self.pos], self.get_char(0)) {
+      ("0", Some('x')) => 16,
+      ("0", Some('o')) => 8,
+      ("0", Some('b')) => 2,
+      _ => 10,
+    };
+    let digits_start = if radix == 10 {
+      self.start
+    } else {
+      self.advance(); // skip the radix letter
+      self.pos
+    };
+
+    // Digits the radix does not allow are scanned too, so that `0b102` is
+    // reported as a bad digit rather than as a bad suffix.
+    let is_digit = |c: char| c == '_' || if radix == 16 { c.is_ascii_hexdigit() } else { c.is_ascii_digit() };
+    let lex_digits = |lexer: &mut Self| {
+      while lexer.check(0, is_digit) {
+        lexer.advance();
+      }
+    };
+
+    lex_digits(self);
+
+    // A hexadecimal fraction must start with a decimal digit or lead up to
+    // the exponent, so that `0xFF.f` stays a field access.
+    let float = radix == 10 || radix == 16;
+    let starts_fraction = |lexer: &Self| {
+      if lexer.check(1, |c| c.is_ascii_digit()) {
+        return true;
+      }
+      if radix != 16 {
+        return false;
+      }
+      let mut offset = 1;
+      while lexer.check(offset, is_digit) {
+        offset += 1;
       }
+      lexer.check(offset, |c| c == 'p' || c == 'P')
+        && lexer.check(offset + 1, |c| c.is_ascii_digit() || c == '+' || c == '-')
     };
+    let mut fraction = false;
+    if float && self.check(0, |c| c == '.') && starts_fraction(self) {
+      self.advance();
+      lex_digits(self);
+      fraction = true;
+    }
 
-    lex_num(self);
+    let marker = if radix == 16 { ['p', 'P'] } else { ['e', 'E'] };
+    let mut exponent = None;
+    if float
+      && self.check(0, |c| marker.contains(&c))
+      && (radix == 16 || self.check(1, |c| c.is_ascii_digit() || c == '+' || c == '-'))
+    {
+      self.advance();
+      let sign_start = self.pos;
+      if self.check(0, |c| c == '+' || c == '-') {
+        self.advance();
+      }
+      let exponent_start = self.pos;
+      while self.check(0, |c| c.is_ascii_digit() || c == '_') {
+        self.advance();
+      }
+      // The sign is kept for evaluation, but does not count as a digit.
+      let digits = &self.source.content()[exponent_start..self.pos];
+      exponent = Some(if !digits.chars().any(|c| c.is_ascii_digit()) {
+        String::new()
+      } else {
+        self.source.content()[sign_start..self.pos].replace('_', "")
+      });
+    }
 
-    if self.check(0, |c| c == '.') && self.check(1, |c| c.is_ascii_digit()) {
+    let digits_end = self.pos;
+    while self.check(0, |c| c.is_xid_continue()) {
       self.advance();
-      lex_num(self);
+    }
 
-      let lexeme = self.make_lexeme();
-      match lexeme.parse::<f64>() {
-        Ok(fval) => self.make_token(TokenKind::Float, Some(TokenData::Float(fval))),
-        Err(_) => self.make_token(
-          TokenKind::Error,
-          Some(TokenData::Error(TokenError::InvalidFloat)),
-        ),
+    let suffix = &self.source.content()[digits_end..self.pos];
+    let result = if fraction || exponent.is_some() || (radix == 10 && FloatSuffix::from_name(suffix).is_some()) {
+      self
+        .float_value(radix, digits_start..digits_end, exponent.as_deref(), fraction)
+        .map_err(TokenError::InvalidFloat)
+    } else {
+      self
+        .integer_value(radix, digits_start..digits_end)
+        .map_err(TokenError::InvalidInteger)
+    };
+
+    match result {
+      Ok(TokenData::Integer(value, suffix)) => {
+        self.make_token(TokenKind::Integer, Some(TokenData::Integer(value, suffix)))
       }
+      Ok(data) => self.make_token(TokenKind::Float, Some(data)),
+      Err(error) => self.make_token(TokenKind::Error, Some(TokenData::Error(error))),
+    }
+  }
+
+  /// Evaluates the float literal whose digits, including any fraction and
+  /// exponent, span `digits` and whose suffix runs from there to the current
+  /// position.
+  fn float_value(
+    &self,
+    radix: u32,
+    digits: Range<usize>,
+    exponent: Option<&str>,
+    fraction: bool,
+  ) -> Result<TokenData, FloatError> {
+    let content = self.source.content();
+
+    let suffix = match &content[digits.end..self.pos] {
+      "" => None,
+      name => match FloatSuffix::from_name(name) {
+        Some(suffix) => Some(suffix),
+        None => return Err(FloatError::UnknownSuffix(digits.end..self.pos)),
+      },
+    };
+    let mantissa = content[digits.clone()].split(['p', 'P']).next().unwrap();
+    if radix == 16 && !mantissa.contains(|c: char| c.is_ascii_hexdigit()) {
+      return Err(FloatError::MissingDigits);
+    }
+    if exponent == Some("") {
+      return Err(FloatError::MissingExponent);
+    }
+    if radix == 16 && fraction && exponent.is_none() {
+      return Err(FloatError::MissingBinaryExponent);
+    }
+
+    let f32 = suffix == Some(FloatSuffix::F32);
+    let (value, inexact, nonzero) = if radix == 16 {
+      hex_float(&content[digits], exponent.unwrap(), f32)
     } else {
-      let lexeme = self.make_lexeme();
-      match lexeme.parse::<i64>() {
-        Ok(ival) => self.make_token(TokenKind::Integer, Some(TokenData::Integer(ival))),
-        Err(_) => self.make_token(
-          TokenKind::Error,
-          Some(TokenData::Error(TokenError::InvalidInteger)),
-        ),
+      let text = content[digits].replace('_', "");
+      let value = if f32 {
+        text.parse::<f32>().unwrap() as f64
+      } else {
+        text.parse::<f64>().unwrap()
+      };
+      // The shortest representation that reads back as the same value has
+      // other digits than the literal exactly when some were rounded away.
+      let shortest = if f32 {
+        format!("{:e}", value as f32)
+      } else {
+        format!("{:e}", value)
+      };
+      let digits = significant_digits(&text);
+      (value, significant_digits(&shortest) != digits, !digits.is_empty())
+    };
+
+    let warning = if value.is_infinite() {
+      Some(FloatWarningKind::Overflow)
+    } else if value == 0.0 && nonzero {
+      Some(FloatWarningKind::Underflow)
+    } else if inexact {
+      Some(FloatWarningKind::Inexact)
+    } else {
+      None
+    };
+    Ok(TokenData::Float(value, suffix, warning))
+  }
+
+  /// Evaluates the integer literal whose digits span `digits` and whose
+  /// suffix runs from there to the current position.
+  fn integer_value(&self, radix: u32, digits: Range<usize>) -> Result<TokenData, IntegerError> {
+    let content = self.source.content();
+
+    let mut value: u128 = 0;
+    let mut seen_digit = false;
+    let mut too_large = false;
+    for (offset, c) in content[digits.clone()].char_indices() {
+      if c == '_' {
+        continue;
+      }
+      let Some(digit) = c.to_digit(radix) else {
+        let start = digits.start + offset;
+        return Err(IntegerError::InvalidDigit {
+          radix,
+          range: start..start + c.len_utf8(),
+        });
+      };
+      seen_digit = true;
+      match value
+        .checked_mul(radix as u128)
+        .and_then(|value| value.checked_add(digit as u128))
+      {
+        Some(next) => value = next,
+        None => too_large = true,
+      }
+    }
+    if !seen_digit {
+      return Err(IntegerError::MissingDigits);
+    }
+
+    let suffix = match &content[digits.end..self.pos] {
+      "" => None,
+      name => match IntegerSuffix::from_name(name) {
+        Some(suffix) => Some(suffix),
+        None => return Err(IntegerError::UnknownSuffix(digits.end..self.pos)),
+      },
+    };
+
+    if too_large {
+      return Err(IntegerError::TooLarge);
+    }
+    if let Some(suffix) = suffix
+      && value > suffix.max()
+    {
+      return Err(IntegerError::OutOfRange(suffix));
+    }
+    Ok(TokenData::Integer(value, suffix))
+  }
+
+  fn lex_escape(&mut self) -> Result<char, TokenError> {
+    let escape_start = self.pos - 1; // the `\` has already been consumed
+    let invalid = |lexer: &Self| TokenError::InvalidEscape(escape_start..lexer.pos);
+
+    let c = match self.get_char(0) {
+      Some(c) if c != '\n' => c,
+      _ => return Err(invalid(self)),
+    };
+    self.advance();
+
+    match c {
+      'n' => Ok('\n'),
+      't' => Ok('\t'),
+      'r' => Ok('\r'),
+      '0' => Ok('\0'),
+      '\\' => Ok('\\'),
+      '"' => Ok('"'),
+      '\'' => Ok('\''),
+      'x' => {
+        let mut value = 0;
+        for _ in 0..2 {
+          match self.get_char(0).and_then(|c| c.to_digit(16)) {
+            Some(digit) => {
+              value = value * 16 + digit;
+              self.advance();
+            }
+            None => return Err(invalid(self)),
+          }
+        }
+
+        if value > 0x7F {
+          return Err(invalid(self));
+        }
+        Ok(char::from(value as u8))
+      }
+      'u' => {
+        if !self.check(0, |c| c == '{') {
+          return Err(invalid(self));
+        }
+        self.advance();
+
+        let mut value = 0;
+        let mut digits = 0;
+        while let Some(digit) = self.get_char(0).and_then(|c| c.to_digit(16)) {
+          if digits < 6 {
+            value = value * 16 + digit;
+          }
+          digits += 1;
+          self.advance();
+        }
+
+        if digits == 0 || digits > 6 || !self.check(0, |c| c == '}') {
+          return Err(invalid(self));
+        }
+        self.advance();
+
+        char::from_u32(value).ok_or_else(|| invalid(self))
+      }
+      _ => Err(invalid(self)),
+    }
+  }
+
+  fn lex_quoted(&mut self, quote: char, unterminated: TokenError) -> Result<String, TokenError> {
+    let mut value = String::new();
+    let mut error = None;
+
+    loop {
+      match self.get_char(0) {
+        None | Some('\n') => return Err(unterminated),
+        Some(c) if c == quote => {
+          self.advance();
+          break;
+        }
+        Some('\\') => {
+          self.advance();
+          match self.lex_escape() {
+            Ok(c) => value.push(c),
+            Err(escape_error) => {
+              error.get_or_insert(escape_error);
+            }
+          }
+        }
+        Some(c) => {
+          value.push(c);
+          self.advance();
+        }
       }
     }
+
+    match error {
+      Some(error) => Err(error),
+      None => Ok(value),
+    }
   }
 
+  fn lex_string(&mut self) -> Token<'src> {
+    match self.lex_quoted('"', TokenError::UnterminatedString) {
+      Ok(value) => self.make_token(TokenKind::String, Some(TokenData::String(value))),
+      Err(error) => self.make_token(TokenKind::Error, Some(TokenData::Error(error))),
+    }
+  }
+
+  fn lex_char(&mut self) -> Token<'src> {
+    let value = self
+      .lex_quoted('\'', TokenError::UnterminatedChar)
+      .and_then(|value| {
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+          (Some(c), None) => Ok(c),
+          _ => Err(TokenError::InvalidChar),
+        }
+      });
+
+    match value {
+      Ok(c) => self.make_token(TokenKind::Char, Some(TokenData::Char(c))),
+      Err(error) => self.make_token(TokenKind::Error, Some(TokenData::Error(error))),
+    }
+  }
+
+  fn lex_compound(&mut self, next: char, compound: TokenKind, single: TokenKind) -> Token<'src> {
+    if self.check(0, |c| c == next) {
+      self.advance();
+      return self.make_token(compound, None);
+    }
+    self.make_token(single, None)
+  }
+
+  /// Lexes the next token other than a doc comment, and keeps the doc
+  /// comments before it in `docs`.
   fn lex(&mut self) -> Token<'src> {
+    if let (Some(first), Some(last)) = (self.docs.first(), self.docs.last()) {
+      let run = first.location().merge(last.location()).unwrap();
+      self.stray_docs.push(run);
+    }
+    self.docs.clear();
+    loop {
+      let token = self.lex_any();
+      match token.kind() {
+        TokenKind::DocComment | TokenKind::InnerDocComment if !self.trivia => {
+          self.docs.push(token)
+        }
+        _ => return token,
+      }
+    }
+  }
+
+  fn lex_any(&mut self) -> Token<'src> {
     if let Some(token) = self.skip_trivial() {
       return token;
     }
@@ -180,20 +612,57 @@ impl<'src> Lexer<'src> {
       Some(c) => {
         self.advance();
         match c {
-          'a'..='z' | 'A'..='Z' | '_' => return self.lex_identifier(),
+          c if c == '_' || c.is_xid_start() => return self.lex_identifier(),
           '0'..='9' => return self.lex_number(),
+          '"' => return self.lex_string(),
+          '\'' => return self.lex_char(),
           ',' => return self.make_token(TokenKind::Comma, None),
           ';' => return self.make_token(TokenKind::Semicolon, None),
-          '+' => return self.make_token(TokenKind::Plus, None),
-          '-' => return self.make_token(TokenKind::Minus, None),
-          '*' => return self.make_token(TokenKind::Star, None),
-          '/' => return self.make_token(TokenKind::Slash, None),
-          '=' => return self.make_token(TokenKind::Assign, None),
+          '+' => return self.lex_compound('=', TokenKind::PlusAssign, TokenKind::Plus),
+          '-' => return self.lex_compound('=', TokenKind::MinusAssign, TokenKind::Minus),
+          '*' => return self.lex_compound('=', TokenKind::StarAssign, TokenKind::Star),
+          '/' => return self.lex_compound('=', TokenKind::SlashAssign, TokenKind::Slash),
+          '%' => return self.lex_compound('=', TokenKind::PercentAssign, TokenKind::Percent),
+          '^' => return self.lex_compound('=', TokenKind::CaretAssign, TokenKind::Caret),
+          '=' if self.check(0, |c| c == '>') => {
+            self.advance();
+            return self.make_token(TokenKind::FatArrow, None);
+          }
+          '=' => return self.lex_compound('=', TokenKind::Equal, TokenKind::Assign),
+          '!' => return self.lex_compound('=', TokenKind::NotEqual, TokenKind::Bang),
+          '<' if self.check(0, |c| c == '<') => {
+            self.advance();
+            return self.lex_compound('=', TokenKind::ShiftLeftAssign, TokenKind::ShiftLeft);
+          }
+          '<' => return self.lex_compound('=', TokenKind::LessEqual, TokenKind::Less),
+          '>' if self.check(0, |c| c == '>') => {
+            self.advance();
+            return self.lex_compound('=', TokenKind::ShiftRightAssign, TokenKind::ShiftRight);
+          }
+          '>' => return self.lex_compound('=', TokenKind::GreaterEqual, TokenKind::Greater),
+          '&' if self.check(0, |c| c == '=') => {
+            self.advance();
+            return self.make_token(TokenKind::AmpersandAssign, None);
+          }
+          '&' => return self.lex_compound('&', TokenKind::LogicalAnd, TokenKind::Ampersand),
+          '|' if self.check(0, |c| c == '=') => {
+            self.advance();
+            return self.make_token(TokenKind::PipeAssign, None);
+          }
+          '|' => return self.lex_compound('|', TokenKind::LogicalOr, TokenKind::Pipe),
           '(' => return self.make_token(TokenKind::Paren(true), None),
           ')' => return self.make_token(TokenKind::Paren(false), None),
           '{' => return self.make_token(TokenKind::Brace(true), None),
           '}' => return self.make_token(TokenKind::Brace(false), None),
-          ':' => return self.make_token(TokenKind::Colon, None),
+          '[' => return self.make_token(TokenKind::Bracket(true), None),
+          ']' => return self.make_token(TokenKind::Bracket(false), None),
+          ':' => return self.lex_compound(':', TokenKind::ColonColon, TokenKind::Colon),
+          '.' if self.check(0, |c| c == '.') && self.check(1, |c| c == '.') => {
+            self.advance();
+            self.advance();
+            return self.make_token(TokenKind::Ellipsis, None);
+          }
+          '.' => return self.make_token(TokenKind::Dot, None),
           _ => {}
         }
       }
@@ -212,10 +681,111 @@ impl<'src> Lexer<'src> {
     self.next.clone().unwrap()
   }
 
+  /// The doc comments directly before the token `peek` returns.
+  pub fn docs(&self) -> &[Token<'src>] {
+    &self.docs
+  }
+
+  /// Takes the doc comments directly before the token `peek` returns, so
+  /// they do not count as stray.
+  pub fn take_docs(&mut self) -> Vec<Token<'src>> {
+    std::mem::take(&mut self.docs)
+  }
+
+  /// Takes the locations of the runs of doc comments lexed past without being
+  /// taken.
+  pub fn take_stray_docs(&mut self) -> Vec<Location<'src>> {
+    std::mem::take(&mut self.stray_docs)
+  }
+
   pub fn reset(&mut self) {
     self.pos = 0;
     self.start = 0;
+    self.docs.clear();
+    self.stray_docs.clear();
+  }
+
+  pub fn checkpoint(&self) -> Checkpoint<'src> {
+    Checkpoint {
+      pos: self.pos,
+      start: self.start,
+      next: self.next.clone(),
+      docs: self.docs.clone(),
+      stray_docs: self.stray_docs.len(),
+    }
+  }
+
+  pub fn restore(&mut self, checkpoint: Checkpoint<'src>) {
+    self.pos = checkpoint.pos;
+    self.start = checkpoint.start;
+    self.next = checkpoint.next;
+    self.docs = checkpoint.docs;
+    self.stray_docs.truncate(checkpoint.stray_docs);
+  }
+}
+
+/// The digits of a decimal number from the first to the last nonzero one,
+/// ignoring the point and any exponent: `0.0120e3` has the digits `12`.
+fn significant_digits(text: &str) -> String {
+  let mantissa = text.split(['e', 'E']).next().unwrap();
+  let digits: String = mantissa.chars().filter(|c| c.is_ascii_digit()).collect();
+  digits.trim_matches('0').to_string()
+}
+
+/// Evaluates the mantissa `digits` and binary `exponent` of a hexadecimal
+/// float. Returns the value, whether it lost bits and whether any digit was
+/// nonzero.
+fn hex_float(digits: &str, exponent: &str, f32: bool) -> (f64, bool, bool) {
+  let mantissa_digits = digits.split(['p', 'P']).next().unwrap();
+
+  let mut mantissa: u128 = 0;
+  let mut shift: i64 = 0;
+  let mut dropped = false;
+  let mut fraction = false;
+  for c in mantissa_digits.chars() {
+    let digit = match c {
+      '_' => continue,
+      '.' => {
+        fraction = true;
+        continue;
+      }
+      _ => c.to_digit(16).unwrap() as u128,
+    };
+    if mantissa >> 124 == 0 {
+      mantissa = mantissa << 4 | digit;
+      if fraction {
+        shift -= 4;
+      }
+    } else {
+      // Digits beyond 128 bits cannot survive rounding to 53 bits anyway.
+      dropped |= digit != 0;
+      if !fraction {
+        shift += 4;
+      }
+    }
+  }
+
+  // An exponent too large for `i64` is far out of range either way.
+  let exponent = exponent.parse::<i64>().unwrap_or(if exponent.starts_with('-') {
+    i64::MIN / 2
+  } else {
+    i64::MAX / 2
+  });
+  let mut power = (exponent + shift).clamp(-4000, 4000) as i32;
+
+  // Scaling in steps keeps the intermediate results normal.
+  let mut value = mantissa as f64;
+  while power != 0 {
+    let step = power.clamp(-1000, 1000);
+    value *= 2f64.powi(step);
+    power -= step;
   }
+
+  let significand = if f32 { f32::MANTISSA_DIGITS } else { f64::MANTISSA_DIGITS };
+  let bits = 128 - mantissa.leading_zeros() - mantissa.trailing_zeros().min(128);
+  let inexact = dropped || (mantissa != 0 && bits > significand);
+  let value = if f32 { value as f32 as f64 } else { value };
+  (value, inexact, mantissa != 0 || dropped)
 }
 
 impl<'src> Iterator for Lexer<'src> {
@@ -231,3 +801,385 @@ impl<'src> Iterator for Lexer<'src> {
     cache
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn lex_all<'src>(arena: &'src TypedArena<Source<'src>>, content: &str) -> Vec<Token<'src>> {
+    let source = Arc::new_in(Source::new(content, "test.sable", arena), arena);
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+    loop {
+      let token = lexer.next().unwrap();
+      if *token.kind() == TokenKind::Eof {
+        break;
+      }
+      tokens.push(token);
+    }
+    tokens
+  }
+
+  #[test]
+  fn test_string_escapes() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, r#""a\n\t\\\"\x41\u{1F600}""#);
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(*tokens[0].kind(), TokenKind::String);
+    assert_eq!(
+      tokens[0].data(),
+      &Some(TokenData::String("a\n\t\\\"A\u{1F600}".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_char_literals() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, r"'a' '\'' '\u{e9}' '' 'ab'");
+
+    let data: Vec<_> = tokens.iter().map(|token| token.data().clone()).collect();
+    assert_eq!(
+      data,
+      vec![
+        Some(TokenData::Char('a')),
+        Some(TokenData::Char('\'')),
+        Some(TokenData::Char('é')),
+        Some(TokenData::Error(TokenError::InvalidChar)),
+        Some(TokenData::Error(TokenError::InvalidChar)),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_string_errors() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "\"ok\\q\" \"open\n'x");
+
+    assert_eq!(
+      tokens[0].data(),
+      &Some(TokenData::Error(TokenError::InvalidEscape(3..5)))
+    );
+    assert_eq!(
+      tokens[1].data(),
+      &Some(TokenData::Error(TokenError::UnterminatedString))
+    );
+    assert_eq!(
+      tokens[2].data(),
+      &Some(TokenData::Error(TokenError::UnterminatedChar))
+    );
+  }
+
+  #[test]
+  fn test_punctuation() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "a::b.c ... => = : _ 1.5 [0]");
+
+    let kinds: Vec<_> = tokens.iter().map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::Identifier,
+        TokenKind::ColonColon,
+        TokenKind::Identifier,
+        TokenKind::Dot,
+        TokenKind::Identifier,
+        TokenKind::Ellipsis,
+        TokenKind::FatArrow,
+        TokenKind::Assign,
+        TokenKind::Colon,
+        TokenKind::Underscore,
+        TokenKind::Float,
+        TokenKind::Bracket(true),
+        TokenKind::Integer,
+        TokenKind::Bracket(false),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_compound_assignment() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "+= -= *= /= %= &= |= ^= <<= >>= && || << >>");
+
+    let kinds: Vec<_> = tokens.iter().map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::PlusAssign,
+        TokenKind::MinusAssign,
+        TokenKind::StarAssign,
+        TokenKind::SlashAssign,
+        TokenKind::PercentAssign,
+        TokenKind::AmpersandAssign,
+        TokenKind::PipeAssign,
+        TokenKind::CaretAssign,
+        TokenKind::ShiftLeftAssign,
+        TokenKind::ShiftRightAssign,
+        TokenKind::LogicalAnd,
+        TokenKind::LogicalOr,
+        TokenKind::ShiftLeft,
+        TokenKind::ShiftRight,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_bool_keywords() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "true false truthy False");
+
+    let kinds: Vec<_> = tokens.iter().map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::True,
+        TokenKind::False,
+        TokenKind::Identifier,
+        TokenKind::Identifier,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_integer_literals() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(
+      &arena,
+      "0xFF 0o17 0b1010 1_000_000 18446744073709551615 255u8 0x7f_i8 128i8",
+    );
+
+    let data: Vec<_> = tokens.iter().map(|token| token.data().clone()).collect();
+    assert_eq!(
+      data,
+      vec![
+        Some(TokenData::Integer(255, None)),
+        Some(TokenData::Integer(15, None)),
+        Some(TokenData::Integer(10, None)),
+        Some(TokenData::Integer(1_000_000, None)),
+        Some(TokenData::Integer(18446744073709551615, None)),
+        Some(TokenData::Integer(255, Some(IntegerSuffix::U8))),
+        Some(TokenData::Integer(127, Some(IntegerSuffix::I8))),
+        Some(TokenData::Integer(128, Some(IntegerSuffix::I8))),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_integer_errors() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(
+      &arena,
+      "0x 0b102 12abc 256u8 340282366920938463463374607431768211456",
+    );
+
+    let errors: Vec<_> = tokens
+      .iter()
+      .map(|token| match token.data() {
+        Some(TokenData::Error(TokenError::InvalidInteger(error))) => error.clone(),
+        data => panic!("expected an integer error, found {:?}", data),
+      })
+      .collect();
+    assert_eq!(
+      errors,
+      vec![
+        IntegerError::MissingDigits,
+        IntegerError::InvalidDigit {
+          radix: 2,
+          range: 7..8,
+        },
+        IntegerError::UnknownSuffix(11..14),
+        IntegerError::OutOfRange(IntegerSuffix::U8),
+        IntegerError::TooLarge,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_float_literals() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(
+      &arena,
+      "1e10 2.5E-3 1_000.5 1.5f32 2f64 0x1.8p3 0x1p-2 0.1 16777217f32 1e400 1e-400",
+    );
+
+    let data: Vec<_> = tokens.iter().map(|token| token.data().clone()).collect();
+    assert_eq!(
+      data,
+      vec![
+        Some(TokenData::Float(1e10, None, None)),
+        Some(TokenData::Float(2.5e-3, None, None)),
+        Some(TokenData::Float(1000.5, None, None)),
+        Some(TokenData::Float(1.5, Some(FloatSuffix::F32), None)),
+        Some(TokenData::Float(2.0, Some(FloatSuffix::F64), None)),
+        Some(TokenData::Float(12.0, None, None)),
+        Some(TokenData::Float(0.25, None, None)),
+        Some(TokenData::Float(0.1, None, None)),
+        Some(TokenData::Float(
+          16777216.0,
+          Some(FloatSuffix::F32),
+          Some(FloatWarningKind::Inexact)
+        )),
+        Some(TokenData::Float(
+          f64::INFINITY,
+          None,
+          Some(FloatWarningKind::Overflow)
+        )),
+        Some(TokenData::Float(0.0, None, Some(FloatWarningKind::Underflow))),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_float_errors() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "1e+ 0x1.8 1.5u8");
+
+    let data: Vec<_> = tokens.iter().map(|token| token.data().clone()).collect();
+    assert_eq!(
+      data,
+      vec![
+        Some(TokenData::Error(TokenError::InvalidFloat(FloatError::MissingExponent))),
+        Some(TokenData::Error(TokenError::InvalidFloat(
+          FloatError::MissingBinaryExponent
+        ))),
+        Some(TokenData::Error(TokenError::InvalidFloat(
+          FloatError::UnknownSuffix(13..15)
+        ))),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_hex_float_edges() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "0xp3 0x1.p3 0xFF.f");
+
+    let kinds: Vec<_> = tokens.iter().map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::Error,
+        TokenKind::Float,
+        TokenKind::Integer,
+        TokenKind::Dot,
+        TokenKind::Identifier,
+      ]
+    );
+    assert_eq!(
+      tokens[0].data(),
+      &Some(TokenData::Error(TokenError::InvalidFloat(FloatError::MissingDigits)))
+    );
+    assert_eq!(tokens[1].data(), &Some(TokenData::Float(8.0, None, None)));
+    assert_eq!(tokens[2].lexeme(), &"0xFF");
+  }
+
+  #[test]
+  fn test_unicode_identifiers() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "größe _x1 变量 cafe\u{301} 1é ∑");
+
+    let kinds: Vec<_> = tokens.iter().map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::Identifier,
+        TokenKind::Identifier,
+        TokenKind::Identifier,
+        TokenKind::Identifier,
+        TokenKind::Error,
+        TokenKind::Error,
+      ]
+    );
+    let lexemes: Vec<_> = tokens.iter().take(4).map(|token| *token.lexeme()).collect();
+    assert_eq!(lexemes, vec!["größe", "_x1", "变量", "cafe\u{301}"]);
+  }
+
+  #[test]
+  fn test_nested_block_comments() {
+    let arena = TypedArena::new();
+    let tokens = lex_all(&arena, "a /* outer /* inner */ still outer */ b /* open /* */");
+
+    let kinds: Vec<_> = tokens.iter().map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![TokenKind::Identifier, TokenKind::Identifier, TokenKind::Error]
+    );
+    assert_eq!(
+      tokens[2].data(),
+      &Some(TokenData::Error(TokenError::UnterminatedComment))
+    );
+  }
+
+  #[test]
+  fn test_doc_comments() {
+    let arena = TypedArena::new();
+    let source = Arc::new_in(
+      Source::new("//! File docs\n// plain\n//// not docs\n/// Adds.\n///\nfunc", "test.sable", &arena),
+      &arena,
+    );
+    let mut lexer = Lexer::new(source);
+
+    assert_eq!(*lexer.peek().kind(), TokenKind::Func);
+    let docs: Vec<_> = lexer
+      .docs()
+      .iter()
+      .map(|token| (*token.kind(), token.doc_text().unwrap()))
+      .collect();
+    assert_eq!(
+      docs,
+      vec![
+        (TokenKind::InnerDocComment, "File docs"),
+        (TokenKind::DocComment, "Adds."),
+        (TokenKind::DocComment, ""),
+      ]
+    );
+
+    lexer.next();
+    assert_eq!(*lexer.peek().kind(), TokenKind::Eof);
+    assert!(lexer.docs().is_empty());
+  }
+
+  #[test]
+  fn test_lossless_round_trip() {
+    let arena = TypedArena::new();
+    let content = concat!(
+      "//! Module docs\r\n",
+      "/// Adds.\n",
+      "func add(a: i32, b: i32): i32 {\t// sum \u{e9}\n",
+      "  /* outer /* inner */ */ a + b\r\n",
+      "}\r \n",
+      "var s = \"a\\qb\"; var c = 'ab'; 0x; 1e+ @ \"open\n",
+      "/* unterminated",
+    );
+    let source = Arc::new_in(Source::new(content, "test.sable", &arena), &arena);
+    let mut lexer = Lexer::lossless(source);
+
+    let mut tokens = Vec::new();
+    loop {
+      let token = lexer.next().unwrap();
+      if *token.kind() == TokenKind::Eof {
+        break;
+      }
+      tokens.push(token);
+    }
+
+    let lexemes: String = tokens.iter().map(|token| *token.lexeme()).collect();
+    assert_eq!(lexemes, content);
+
+    let kinds: Vec<_> = tokens.iter().take(5).map(|token| *token.kind()).collect();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::InnerDocComment,
+        TokenKind::Newline,
+        TokenKind::DocComment,
+        TokenKind::Newline,
+        TokenKind::Func,
+      ]
+    );
+    assert!(tokens.iter().any(|token| *token.kind() == TokenKind::LineComment));
+    assert!(tokens.iter().any(|token| *token.kind() == TokenKind::BlockComment));
+    assert!(tokens.iter().any(|token| *token.kind() == TokenKind::Whitespace));
+  }
+}
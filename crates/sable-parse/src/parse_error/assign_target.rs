@@ -0,0 +1,38 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug)]
+pub struct AssignTargetError<'src> {
+  target: Location<'src>,
+}
+
+impl<'src> AssignTargetError<'src> {
+  pub fn new(target: Location<'src>) -> Self {
+    Self { target }
+  }
+}
+
+impl<'src> Reportable<'src> for AssignTargetError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.target.filename(), self.target.range().clone());
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message("Cannot assign to this expression")
+      .with_label(
+        Label::new(span)
+          .with_message("Only variables, fields, array elements and dereferenced pointers can be assigned to"),
+      )
+      .finish()
+  }
+}
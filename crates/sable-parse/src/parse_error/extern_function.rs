@@ -0,0 +1,55 @@
+use ariadne::{
+  Label,
+  Report,
+  ReportKind,
+};
+use sable_common::{
+  file::Span,
+  location::Location,
+  writer::{
+    Reportable,
+    build_report,
+  },
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum ExternFunctionErrorKind {
+  UnsupportedAbi,
+  Body,
+  VariadicDefinition,
+}
+
+#[derive(Debug)]
+pub struct ExternFunctionError<'src> {
+  location: Location<'src>,
+  kind: ExternFunctionErrorKind,
+}
+
+impl<'src> ExternFunctionError<'src> {
+  pub fn new(location: Location<'src>, kind: ExternFunctionErrorKind) -> Self {
+    Self { location, kind }
+  }
+}
+
+impl<'src> Reportable<'src> for ExternFunctionError<'src> {
+  fn report(&self) -> Report<'_, Span<'src>> {
+    let span: Span = (*self.location.filename(), self.location.range().clone());
+
+    let (message, label) = match self.kind {
+      ExternFunctionErrorKind::UnsupportedAbi => ("Unsupported ABI", "Only \"C\" is supported"),
+      ExternFunctionErrorKind::Body => (
+        "External function cannot have a body",
+        "Remove the body and end the declaration with `;`",
+      ),
+      ExternFunctionErrorKind::VariadicDefinition => (
+        "Only external functions can be variadic",
+        "`...` requires an `extern \"C\"` declaration",
+      ),
+    };
+
+    build_report(ReportKind::Error, span.clone())
+      .with_message(message)
+      .with_label(Label::new(span).with_message(label))
+      .finish()
+  }
+}
@@ -1,3 +1,5 @@
+pub mod assign_target;
+pub mod extern_function;
 pub mod unexpected_token;
 
 use ariadne::Report;
@@ -8,28 +10,42 @@ use smallvec::SmallVec;
 use crate::{
   lex_error::{
     comment_error::CommentError,
+    escape_error::EscapeError,
     numeric_error::NumericError,
+    string_error::StringError,
     unknown_char::UnknownCharError,
   },
-  parse_error::unexpected_token::UnexpectedTokenError,
+  parse_error::{
+    assign_target::AssignTargetError,
+    extern_function::ExternFunctionError,
+    unexpected_token::UnexpectedTokenError,
+  },
 };
 use sable_common::writer::Reportable;
 
 #[derive(Debug)]
 pub enum ParseError<'src> {
   UnexpectedToken(UnexpectedTokenError<'src>),
+  AssignTarget(AssignTargetError<'src>),
+  ExternFunction(ExternFunctionError<'src>),
   UnknownChar(UnknownCharError<'src>),
   NumericError(NumericError<'src>),
   CommentError(CommentError<'src>),
+  StringError(StringError<'src>),
+  EscapeError(EscapeError<'src>),
 }
 
 impl<'src> Reportable<'src> for ParseError<'src> {
   fn report(&self) -> Report<'_, Span<'src>> {
     match self {
       ParseError::UnexpectedToken(unexpected_token) => unexpected_token.report(),
+      ParseError::AssignTarget(assign_target) => assign_target.report(),
+      ParseError::ExternFunction(extern_function) => extern_function.report(),
       ParseError::UnknownChar(unknown_char) => unknown_char.report(),
       ParseError::NumericError(numeric_error) => numeric_error.report(),
       ParseError::CommentError(comment_error) => comment_error.report(),
+      ParseError::StringError(string_error) => string_error.report(),
+      ParseError::EscapeError(escape_error) => escape_error.report(),
     }
   }
 }
@@ -11,7 +11,10 @@ use sable_ast::token::{
 use sable_common::file::Span;
 use smallvec::SmallVec;
 
-use sable_common::writer::Reportable;
+use sable_common::writer::{
+  Reportable,
+  build_report,
+};
 
 pub const MAX_INLINE_KINDS: usize = 8;
 
@@ -44,7 +47,7 @@ impl<'src> Reportable<'src> for UnexpectedTokenError<'src> {
         .join(", ")
     );
 
-    Report::build(ReportKind::Error, span.clone())
+    build_report(ReportKind::Error, span.clone())
       .with_message(format!("Unexpected token: `{:?}`", self.found.kind()))
       .with_label(Label::new(span).with_message(expected))
       .finish()
@@ -1,17 +1,36 @@
 #![allow(clippy::result_large_err)]
 #![allow(clippy::result_unit_err)]
 
-use std::mem::MaybeUninit;
+use std::ops::Range;
 
 use crate::{
   lex_error::{
     comment_error::CommentError,
-    numeric_error::NumericError,
+    doc_warning::DocWarning,
+    escape_error::EscapeError,
+    float_warning::FloatWarning,
+    identifier_warning::{
+      IdentifierWarning,
+      IdentifierWarningKind,
+    },
+    numeric_error::{
+      NumericError,
+      NumericErrorKind,
+    },
+    string_error::{
+      StringError,
+      StringErrorKind,
+    },
     unknown_char::UnknownCharError,
   },
   parse_error::{
     ParseError,
     ParseErrorMOO,
+    assign_target::AssignTargetError,
+    extern_function::{
+      ExternFunctionError,
+      ExternFunctionErrorKind,
+    },
     unexpected_token::{
       MAX_INLINE_KINDS,
       UnexpectedTokenError,
@@ -19,39 +38,91 @@ use crate::{
   },
 };
 use either::Either;
+use indexmap::IndexMap;
 use sable_ast::{
   ast::Ast,
   expression::{
+    ArrayExpression,
     AssignExpression,
     BinaryExpression,
+    BinaryOperator,
     BlockExpression,
+    CallExpression,
+    CastExpression,
     Expression,
     ExpressionKind,
+    FieldExpression,
     IdentifierExpression,
+    IfExpression,
+    IndexExpression,
     LiteralExpression,
-    binary_expression::{
-      AddExpression,
-      DivideExpression,
-      MultiplyExpression,
-      SubtractExpression,
+    LoopExpression,
+    MatchExpression,
+    StructLiteralExpression,
+    UnaryExpression,
+    UnaryOperator,
+    WhileExpression,
+    array_expression::{
+      ArrayListExpression,
+      ArrayRepeatExpression,
+    },
+    call_expression::MAX_INLINE_ARGS,
+    match_expression::{
+      MAX_INLINE_ARMS,
+      MatchArm,
     },
     literal_expression::{
+      BoolExpression,
+      CharExpression,
       FloatExpression,
       IntegerExpression,
+      StringExpression,
     },
+    struct_literal_expression::FieldInitializer,
   },
   located::Located,
-  objects::function::{
-    Function,
-    FunctionParam,
-    MAX_INLINE_PARAMS,
+  objects::{
+    alias::TypeAlias,
+    enumeration::{
+      Enum,
+      EnumVariant,
+      MAX_INLINE_VARIANTS,
+    },
+    function::{
+      Function,
+      FunctionParam,
+      MAX_INLINE_PARAMS,
+    },
+    global::{
+      Global,
+      GlobalKind,
+    },
+    import::{
+      Import,
+      ImportKind,
+    },
+    structure::{
+      MAX_INLINE_FIELDS,
+      Struct,
+      StructField,
+    },
+  },
+  pattern::{
+    Pattern,
+    PatternKind,
+    VariantPattern,
   },
   statement::{
+    BreakStatement,
+    ReturnStatement,
     Statement,
     StatementKind,
     VariableStatement,
   },
   token::{
+    FloatError,
+    IntegerError,
+    IntegerSuffix,
     Token,
     TokenData,
     TokenError,
@@ -64,7 +135,10 @@ use sable_ast::{
   },
 };
 use sable_common::{
-  interner::StrInterner,
+  interner::{
+    Entry,
+    StrInterner,
+  },
   location::Location,
   writer::{
     Reportable,
@@ -75,6 +149,11 @@ use smallvec::{
   SmallVec,
   smallvec,
 };
+use unicode_normalization::{
+  UnicodeNormalization,
+  is_nfc,
+};
+use unicode_security::MixedScript;
 
 use crate::lexer::Lexer;
 
@@ -98,11 +177,119 @@ fn expected_expression() -> SmallVec<[TokenKind; MAX_INLINE_KINDS]> {
   smallvec![
     TokenKind::Integer,
     TokenKind::Float,
+    TokenKind::String,
+    TokenKind::Char,
+    TokenKind::True,
+    TokenKind::False,
     TokenKind::Identifier,
-    TokenKind::Paren(true)
+    TokenKind::Paren(true),
+    TokenKind::Bracket(true),
+    TokenKind::If,
+    TokenKind::While,
+    TokenKind::Loop,
+    TokenKind::Match,
+    TokenKind::Minus,
+    TokenKind::Bang,
+    TokenKind::Ampersand,
+    TokenKind::LogicalAnd,
+    TokenKind::Star
+  ]
+}
+
+fn block_like_expression() -> SmallVec<[TokenKind; MAX_INLINE_KINDS]> {
+  smallvec![
+    TokenKind::If,
+    TokenKind::While,
+    TokenKind::Loop,
+    TokenKind::Match,
+  ]
+}
+
+/// Where top-level error recovery resumes. `var` is left out: it also starts
+/// local statements, so syncing on it would stop inside the broken item.
+fn item_start() -> SmallVec<[TokenKind; MAX_INLINE_KINDS]> {
+  smallvec![
+    TokenKind::Func,
+    TokenKind::Extern,
+    TokenKind::Struct,
+    TokenKind::Enum,
+    TokenKind::Const,
+    TokenKind::Type,
+    TokenKind::Import,
+  ]
+}
+
+fn unary_operators() -> SmallVec<[TokenKind; MAX_INLINE_KINDS]> {
+  smallvec![
+    TokenKind::Minus,
+    TokenKind::Bang,
+    TokenKind::Ampersand,
+    TokenKind::LogicalAnd,
+    TokenKind::Star
   ]
 }
 
+/// Binary operators with their binding power, loosest first. Mirrors C.
+const BINARY_OPERATORS: &[(TokenKind, BinaryOperator, u8)] = &[
+  (TokenKind::LogicalOr, BinaryOperator::LogicalOr, 1),
+  (TokenKind::LogicalAnd, BinaryOperator::LogicalAnd, 2),
+  (TokenKind::Pipe, BinaryOperator::BitOr, 3),
+  (TokenKind::Caret, BinaryOperator::BitXor, 4),
+  (TokenKind::Ampersand, BinaryOperator::BitAnd, 5),
+  (TokenKind::Equal, BinaryOperator::Equal, 6),
+  (TokenKind::NotEqual, BinaryOperator::NotEqual, 6),
+  (TokenKind::Less, BinaryOperator::Less, 7),
+  (TokenKind::LessEqual, BinaryOperator::LessEqual, 7),
+  (TokenKind::Greater, BinaryOperator::Greater, 7),
+  (TokenKind::GreaterEqual, BinaryOperator::GreaterEqual, 7),
+  (TokenKind::ShiftLeft, BinaryOperator::ShiftLeft, 8),
+  (TokenKind::ShiftRight, BinaryOperator::ShiftRight, 8),
+  (TokenKind::Plus, BinaryOperator::Add, 9),
+  (TokenKind::Minus, BinaryOperator::Subtract, 9),
+  (TokenKind::Star, BinaryOperator::Multiply, 10),
+  (TokenKind::Slash, BinaryOperator::Divide, 10),
+  (TokenKind::Percent, BinaryOperator::Remainder, 10),
+];
+
+fn binary_operator(kind: TokenKind) -> Option<(BinaryOperator, u8)> {
+  BINARY_OPERATORS
+    .iter()
+    .find(|(token, _, _)| *token == kind)
+    .map(|(_, operator, precedence)| (*operator, *precedence))
+}
+
+/// `Assign` and the compound assignments with the operator they apply.
+const ASSIGN_OPERATORS: &[(TokenKind, Option<BinaryOperator>)] = &[
+  (TokenKind::Assign, None),
+  (TokenKind::PlusAssign, Some(BinaryOperator::Add)),
+  (TokenKind::MinusAssign, Some(BinaryOperator::Subtract)),
+  (TokenKind::StarAssign, Some(BinaryOperator::Multiply)),
+  (TokenKind::SlashAssign, Some(BinaryOperator::Divide)),
+  (TokenKind::PercentAssign, Some(BinaryOperator::Remainder)),
+  (TokenKind::AmpersandAssign, Some(BinaryOperator::BitAnd)),
+  (TokenKind::PipeAssign, Some(BinaryOperator::BitOr)),
+  (TokenKind::CaretAssign, Some(BinaryOperator::BitXor)),
+  (TokenKind::ShiftLeftAssign, Some(BinaryOperator::ShiftLeft)),
+  (TokenKind::ShiftRightAssign, Some(BinaryOperator::ShiftRight)),
+];
+
+fn assign_operator(kind: TokenKind) -> Option<Option<BinaryOperator>> {
+  ASSIGN_OPERATORS
+    .iter()
+    .find(|(token, _)| *token == kind)
+    .map(|(_, operator)| *operator)
+}
+
+fn is_assignable(expr: &Expression<'_, '_>) -> bool {
+  match expr.kind() {
+    ExpressionKind::Identifier(_) => true,
+    ExpressionKind::Unary(unary) => *unary.operator() == UnaryOperator::Deref,
+    ExpressionKind::Field(_) => true,
+    ExpressionKind::Index(_) => true,
+    _ => false,
+  }
+}
+
 pub struct Parser<'parser, 'src, 'ast, D>
 where
   D: Sink<'src> + ?Sized,
@@ -111,6 +298,15 @@ where
   ast: &'parser mut Ast<'ast, 'src>,
   sink: &'parser mut D,
   strintern: &'parser StrInterner<'src>,
+  /// Cleared while parsing `if`/`while` conditions so that `if x { ... }`
+  /// opens the body instead of a struct literal `x { ... }`.
+  struct_literals: bool,
+  /// Set when the closing `>` of a nested type argument list was taken from a
+  /// `>>` token, leaving the second `>` for the enclosing list.
+  split_shift: bool,
+  /// Where each identifier first occurs, for the lint against identifiers
+  /// that look alike.
+  identifiers: IndexMap<Entry, Location<'src>>,
 }
 
 impl<'parser, 'src, 'ast, D> Parser<'parser, 'src, 'ast, D>
@@ -130,6 +326,57 @@ where
       ast,
       sink,
       strintern,
+      struct_literals: true,
+      split_shift: false,
+      identifiers: IndexMap::new(),
+    }
+  }
+
+  /// Interns an identifier in Unicode normal form C, so that differently
+  /// encoded spellings of a name, like `ö` as one code point or as `o` and a
+  /// combining diaeresis, are the same name.
+  fn intern_identifier(&mut self, token: &Token<'src>) -> Entry {
+    let lexeme = token.lexeme();
+    let entry = if is_nfc(lexeme) {
+      self.strintern.intern(lexeme)
+    } else {
+      self.strintern.intern(&lexeme.nfc().collect::<String>())
+    };
+    self
+      .identifiers
+      .entry(entry)
+      .or_insert_with(|| token.location().clone());
+    entry
+  }
+
+  /// Warns about identifiers that mix scripts and about distinct identifiers
+  /// that look the same, as defined by UTS #39. Identifiers that are both
+  /// ASCII are never reported as confusable, since `rn` and `m` are unlikely
+  /// to be mistaken for each other in code.
+  fn lint_identifiers(&mut self) {
+    let mut skeletons: IndexMap<String, (&'src str, Location<'src>)> = IndexMap::new();
+    for (entry, location) in std::mem::take(&mut self.identifiers) {
+      let name = self.strintern.resolve(entry).unwrap();
+      if !name.is_ascii() && !name.is_single_script() {
+        let warning = IdentifierWarning::new(name, location.clone(), IdentifierWarningKind::MixedScript);
+        self.sink.report(warning.report()).unwrap();
+      }
+
+      let skeleton: String = unicode_security::skeleton(name).collect();
+      match skeletons.get(&skeleton) {
+        Some((other, other_location)) if !(name.is_ascii() && other.is_ascii()) => {
+          let kind = IdentifierWarningKind::Confusable {
+            other,
+            other_location: other_location.clone(),
+          };
+          let warning = IdentifierWarning::new(name, location, kind);
+          self.sink.report(warning.report()).unwrap();
+        }
+        Some(_) => {}
+        None => {
+          skeletons.insert(skeleton, (name, location));
+        }
+      }
     }
   }
 
@@ -139,12 +386,77 @@ where
         token.lexeme(),
         token.location().clone(),
       )),
-      TokenError::InvalidInteger | TokenError::InvalidFloat => {
-        ParseError::NumericError(NumericError::new(token.lexeme(), token.location().clone()))
+      TokenError::InvalidInteger(_) | TokenError::InvalidFloat(_) => {
+        let offset = token.location().range().start;
+        let part = |range: &Range<usize>| {
+          (
+            &token.lexeme()[range.start - offset..range.end - offset],
+            Location::new(range.clone(), token.location().filename()),
+          )
+        };
+        let kind = match error {
+          TokenError::InvalidFloat(FloatError::MissingDigits) => NumericErrorKind::MissingDigits,
+          TokenError::InvalidFloat(FloatError::MissingExponent) => NumericErrorKind::MissingExponent,
+          TokenError::InvalidFloat(FloatError::MissingBinaryExponent) => {
+            NumericErrorKind::MissingBinaryExponent
+          }
+          TokenError::InvalidFloat(FloatError::UnknownSuffix(range)) => {
+            let (suffix, location) = part(range);
+            NumericErrorKind::UnknownSuffix {
+              suffix,
+              location,
+              float: true,
+            }
+          }
+          TokenError::InvalidInteger(IntegerError::MissingDigits) => NumericErrorKind::MissingDigits,
+          TokenError::InvalidInteger(IntegerError::InvalidDigit { radix, range }) => {
+            let (digit, location) = part(range);
+            NumericErrorKind::InvalidDigit {
+              digit,
+              radix: *radix,
+              location,
+            }
+          }
+          TokenError::InvalidInteger(IntegerError::UnknownSuffix(range)) => {
+            let (suffix, location) = part(range);
+            NumericErrorKind::UnknownSuffix {
+              suffix,
+              location,
+              float: false,
+            }
+          }
+          TokenError::InvalidInteger(IntegerError::TooLarge) => NumericErrorKind::TooLarge,
+          TokenError::InvalidInteger(IntegerError::OutOfRange(suffix)) => {
+            NumericErrorKind::OutOfRange(*suffix)
+          }
+          _ => unreachable!("Not a numeric error"),
+        };
+        ParseError::NumericError(NumericError::new(token.lexeme(), token.location().clone(), kind))
       }
       TokenError::UnterminatedComment => {
         ParseError::CommentError(CommentError::new(token.lexeme(), token.location().clone()))
       }
+      TokenError::UnterminatedString => ParseError::StringError(StringError::new(
+        token.lexeme(),
+        token.location().clone(),
+        StringErrorKind::UnterminatedString,
+      )),
+      TokenError::UnterminatedChar => ParseError::StringError(StringError::new(
+        token.lexeme(),
+        token.location().clone(),
+        StringErrorKind::UnterminatedChar,
+      )),
+      TokenError::InvalidChar => ParseError::StringError(StringError::new(
+        token.lexeme(),
+        token.location().clone(),
+        StringErrorKind::InvalidChar,
+      )),
+      TokenError::InvalidEscape(range) => {
+        let offset = token.location().range().start;
+        let lexeme = &token.lexeme()[range.start - offset..range.end - offset];
+        let location = Location::new(range.clone(), token.location().filename());
+        ParseError::EscapeError(EscapeError::new(lexeme, location, token.location().clone()))
+      }
     }
   }
 
@@ -199,22 +511,249 @@ where
     }
   }
 
-  fn parse_type(&mut self) -> Result<(Type<'src>, Location<'src>), ParseError<'src>> {
-    let token = self.expect(smallvec![TokenKind::Identifier])?;
+  fn parse_path(&mut self) -> Result<Path<'src>, ParseError<'src>> {
+    let mut segments = Vec::new();
+    loop {
+      let token = self.expect(smallvec![TokenKind::Identifier])?;
+      segments.push(
+        Located::builder()
+          .value(self.intern_identifier(&token))
+          .location(token.location().clone())
+          .build(),
+      );
 
-    let segment_located = Located::builder()
-      .value(self.strintern.intern(token.lexeme()))
-      .location(token.location().clone())
-      .build();
+      if self.peek(smallvec![TokenKind::ColonColon]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::ColonColon])?;
+    }
+
+    Ok(Path::builder().segments(segments).build())
+  }
+
+  fn parse_generic_params(&mut self) -> Result<Vec<Located<'src, Entry>>, ParseError<'src>> {
+    let mut generics = Vec::new();
+    if self.peek(smallvec![TokenKind::Less]).is_none() {
+      return Ok(generics);
+    }
+
+    self.expect(smallvec![TokenKind::Less])?;
+    while self.peek(smallvec![TokenKind::Greater]).is_none() {
+      let param = self.expect(smallvec![TokenKind::Identifier])?;
+      generics.push(
+        Located::builder()
+          .value(self.intern_identifier(&param))
+          .location(param.location().clone())
+          .build(),
+      );
+      if self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::Comma])?;
+    }
+    self.expect(smallvec![TokenKind::Greater])?;
+
+    Ok(generics)
+  }
+
+  fn expect_closing_angle(&mut self) -> Result<(), ParseError<'src>> {
+    if self.split_shift {
+      self.split_shift = false;
+      return Ok(());
+    }
+    if self.peek(smallvec![TokenKind::ShiftRight]).is_some() {
+      self.lexer.next();
+      self.split_shift = true;
+      return Ok(());
+    }
+    self.expect(smallvec![TokenKind::Greater])?;
+    Ok(())
+  }
+
+  /// Parses `<T, U>` after a path.
+  fn parse_type_args(&mut self) -> Result<Vec<Type<'src>>, ParseError<'src>> {
+    self.expect(smallvec![TokenKind::Less])?;
+
+    let mut args = Vec::new();
+    loop {
+      let (ty, _) = self.parse_type()?;
+      args.push(ty);
+      if self.split_shift || self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::Comma])?;
+    }
+    self.expect_closing_angle()?;
 
-    let mut ty = Type::Path(Path::builder().segments(vec![segment_located]).build());
+    Ok(args)
+  }
+
+  /// Tries to parse type arguments in expression position, where `<` may also
+  /// be a comparison. They are only kept if the list is directly followed by a
+  /// call or a struct literal, as in `max<i32>(a, b)`.
+  fn parse_instantiation(&mut self, path: &mut Path<'src>) {
+    if self.peek(smallvec![TokenKind::Less]).is_none() {
+      return;
+    }
+
+    let checkpoint = self.lexer.checkpoint();
+    let instantiated = match self.parse_type_args() {
+      Ok(args) if !self.split_shift => {
+        let opens_literal =
+          self.struct_literals && self.peek(smallvec![TokenKind::Brace(true)]).is_some();
+        if self.peek(smallvec![TokenKind::Paren(true)]).is_some() || opens_literal {
+          path.set_generics(args);
+          true
+        } else {
+          false
+        }
+      }
+      _ => false,
+    };
+
+    if !instantiated {
+      self.split_shift = false;
+      self.lexer.restore(checkpoint);
+    }
+  }
+
+  fn parse_type(&mut self) -> Result<(Type<'src>, Location<'src>), ParseError<'src>> {
+    self.parse_type_in(false)
+  }
+
+  /// Parses a type, which after `as` is followed by an expression operator
+  /// rather than a delimiter. There `x as i32 < y` is a comparison unless the
+  /// `<` opens a valid type argument list, and `x as i64 * y` multiplies; a
+  /// `*` is only a pointer suffix when no operand follows it.
+  fn parse_type_in(&mut self, cast: bool) -> Result<(Type<'src>, Location<'src>), ParseError<'src>> {
+    let (mut ty, location) = if self.peek(smallvec![TokenKind::Bracket(true)]).is_some() {
+      self.parse_array_type()?
+    } else if self.peek(smallvec![TokenKind::Func]).is_some() {
+      self.parse_function_type()?
+    } else {
+      let mut path = self.parse_path()?;
+      let location = path.location();
+      if self.peek(smallvec![TokenKind::Less]).is_some() {
+        if cast {
+          let checkpoint = self.lexer.checkpoint();
+          match self.parse_type_args() {
+            Ok(args) => {
+              path.set_generics(args);
+            }
+            Err(_) => {
+              self.split_shift = false;
+              self.lexer.restore(checkpoint);
+            }
+          }
+        } else {
+          let args = self.parse_type_args()?;
+          path.set_generics(args);
+        }
+      }
+      (Type::Path(path), location)
+    };
 
-    while self.peek(smallvec![TokenKind::Star]).is_some() {
+    // After a split `>>` the remaining `>` still closes an enclosing list, so
+    // any `*` that follows belongs to the outer type.
+    while !self.split_shift
+      && self.peek(smallvec![TokenKind::Star]).is_some()
+      && (!cast || !self.operand_follows_star())
+    {
       self.expect(smallvec![TokenKind::Star])?;
       ty = Type::Pointer(Box::new(ty));
     }
 
-    Ok((ty, token.location().clone()))
+    Ok((ty, location))
+  }
+
+  /// Whether the token after the upcoming `*` can only start an operand, which
+  /// makes the `*` a multiplication.
+  fn operand_follows_star(&mut self) -> bool {
+    let checkpoint = self.lexer.checkpoint();
+    self.lexer.next();
+    let follows = matches!(
+      self.lexer.peek().kind(),
+      TokenKind::Integer
+        | TokenKind::Float
+        | TokenKind::String
+        | TokenKind::Char
+        | TokenKind::True
+        | TokenKind::False
+        | TokenKind::Identifier
+        | TokenKind::Paren(true)
+        | TokenKind::Bracket(true)
+        | TokenKind::If
+        | TokenKind::While
+        | TokenKind::Loop
+        | TokenKind::Match
+        | TokenKind::Bang
+    );
+    self.lexer.restore(checkpoint);
+    follows
+  }
+
+  /// `func(A, B): R`. The return type takes any `*` that follows, so
+  /// `func(): i32*` returns a pointer.
+  fn parse_function_type(&mut self) -> Result<(Type<'src>, Location<'src>), ParseError<'src>> {
+    let keyword = self.expect(smallvec![TokenKind::Func])?;
+    self.expect(smallvec![TokenKind::Paren(true)])?;
+
+    let split_shift = std::mem::replace(&mut self.split_shift, false);
+    let params = self.parse_function_type_params();
+    self.split_shift = split_shift;
+    let params = params?;
+
+    self.expect(smallvec![TokenKind::Colon])?;
+    let (return_type, return_location) = self.parse_type()?;
+
+    Ok((
+      Type::Function(params, Box::new(return_type)),
+      keyword.location().merge(&return_location).unwrap(),
+    ))
+  }
+
+  fn parse_function_type_params(&mut self) -> Result<Vec<Type<'src>>, ParseError<'src>> {
+    let mut params = Vec::new();
+    while self.peek(smallvec![TokenKind::Paren(false)]).is_none() {
+      params.push(self.parse_type()?.0);
+      if self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::Comma])?;
+    }
+    self.expect(smallvec![TokenKind::Paren(false)])?;
+    Ok(params)
+  }
+
+  /// `[T; N]` or `[T]`.
+  fn parse_array_type(&mut self) -> Result<(Type<'src>, Location<'src>), ParseError<'src>> {
+    let open = self.expect(smallvec![TokenKind::Bracket(true)])?;
+    let split_shift = std::mem::replace(&mut self.split_shift, false);
+    let element = self.parse_type();
+    self.split_shift = split_shift;
+    let element = Box::new(element?.0);
+
+    let ty = if self.peek(smallvec![TokenKind::Semicolon]).is_some() {
+      self.expect(smallvec![TokenKind::Semicolon])?;
+      let length = self.expect(smallvec![TokenKind::Integer])?;
+      let length = match length.data() {
+        Some(TokenData::Integer(value, _)) => u64::try_from(*value).map_err(|_| {
+          let kind = NumericErrorKind::OutOfRange(IntegerSuffix::U64);
+          ParseError::NumericError(NumericError::new(
+            length.lexeme(),
+            length.location().clone(),
+            kind,
+          ))
+        })?,
+        _ => unreachable!("Integer token missing data"),
+      };
+      Type::Array(element, length)
+    } else {
+      Type::Slice(element)
+    };
+
+    let close = self.expect(smallvec![TokenKind::Bracket(false)])?;
+    Ok((ty, open.location().merge(close.location()).unwrap()))
   }
 
   fn parse_tn_pair(&mut self) -> Result<TypeNamePair<'src>, ParseError<'src>> {
@@ -225,7 +764,7 @@ where
     let location = name_token.location().merge(&location).unwrap();
     Ok(
       TypeNamePair::builder()
-        .name(self.strintern.intern(name_token.lexeme()))
+        .name(self.intern_identifier(&name_token))
         .type_(ty)
         .location(location)
         .build(),
@@ -233,420 +772,2316 @@ where
   }
 
   fn parse_identifier(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
-    let identifier = self.expect(smallvec![TokenKind::Identifier])?;
-
-    let maybe_next = match self.peek(smallvec![TokenKind::Assign]) {
-      Some(got) => got,
-      _ => {
-        let id_expr = IdentifierExpression::builder()
-          .name(self.strintern.intern(identifier.lexeme()))
-          .build();
-        return Ok(
-          Expression::builder()
-            .location(identifier.location().clone())
-            .kind(ExpressionKind::Identifier(id_expr))
-            .build(),
-        );
-      }
-    };
+    let mut path = self.parse_path()?;
+    self.parse_instantiation(&mut path);
 
-    switch!(maybe_next => {
-      TokenKind::Assign => {
-        self.expect(smallvec![TokenKind::Assign])?;
-        let value = self.parse_expression()?;
-        let value_heaped = self.ast.expr_arena().alloc(value);
+    if self.struct_literals && self.peek(smallvec![TokenKind::Brace(true)]).is_some() {
+      return self.parse_struct_literal(path);
+    }
 
-        let identifier_located = Located::builder()
-          .value(self.strintern.intern(identifier.lexeme()))
-          .location(identifier.location().clone())
-          .build();
-        let assign_expr = AssignExpression::builder()
-          .identifier(identifier_located)
-          .value(value_heaped)
-          .build();
+    let location = path.location();
+    let id_expr = IdentifierExpression::builder().path(path).build();
 
-        Ok(
-          Expression::builder()
-            .location(identifier.location().clone())
-            .kind(ExpressionKind::Assign(assign_expr))
-            .build(),
-        )
-      }
-    })
+    Ok(
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::Identifier(id_expr))
+        .build(),
+    )
   }
 
-  fn parse_factor(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
-    let expected = expected_expression();
-    let expr_type = match self.peek(expected.clone()) {
-      Some(kind) => kind,
-      None => {
-        self.expect(expected.clone())?;
-        unreachable!("Expected error but got a valid token")
-      }
-    };
-
-    switch!(expr_type => {
-      TokenKind::Integer => {
-        let value_expr = self.expect(smallvec![TokenKind::Integer])?;
+  fn parse_struct_literal(
+    &mut self,
+    path: Path<'src>,
+  ) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Brace(true)])?;
 
-        let value = match value_expr.data() {
-          Some(TokenData::Integer(value)) => value,
-          _ => unreachable!("Integer token missing data"),
-        };
+    let mut pre_fields = SmallVec::<[FieldInitializer<'ast, 'src>; MAX_INLINE_FIELDS]>::new();
+    while self.peek(smallvec![TokenKind::Brace(false)]).is_none() {
+      let field_token = self.expect(smallvec![TokenKind::Identifier])?;
+      self.expect(smallvec![TokenKind::Colon])?;
+      let value = self.parse_expression()?;
 
-        let int_expr = IntegerExpression::builder()
-          .value(*value)
-          .build();
+      let field_name = Located::builder()
+        .value(self.intern_identifier(&field_token))
+        .location(field_token.location().clone())
+        .build();
+      pre_fields.push(
+        FieldInitializer::builder()
+          .name(field_name)
+          .value(value)
+          .build(),
+      );
 
-        Ok(
-          Expression::builder()
-            .location(value_expr.location().clone())
-            .kind(ExpressionKind::Literal(LiteralExpression::Integer(int_expr)))
-            .build(),
-        )
-      },
-      TokenKind::Float => {
-        let value_expr = self.expect(smallvec![TokenKind::Float])?;
+      if self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::Comma])?;
+    }
 
-        let value = match value_expr.data() {
-          Some(TokenData::Float(value)) => value,
-          _ => unreachable!("Float token missing data"),
-        };
+    let close = self.expect(smallvec![TokenKind::Brace(false)])?;
+    let location = path.location().merge(close.location()).unwrap();
 
-        let float_expr = FloatExpression::builder()
-          .value(*value)
-          .build();
+    let field_count = pre_fields.len();
+    let mut pre_fields = pre_fields.into_iter();
+    let fields = self
+      .ast
+      .field_init_arena()
+      .alloc_slice_with(field_count, |_| pre_fields.next().unwrap());
 
-        Ok(
-          Expression::builder()
-            .location(value_expr.location().clone())
-            .kind(ExpressionKind::Literal(LiteralExpression::Float(float_expr)))
-            .build(),
-        )
-      },
-      TokenKind::Identifier => Ok(self.parse_identifier()?),
-      TokenKind::Paren(true) => {
-        self.expect(smallvec![TokenKind::Paren(true)])?;
-        let expr = self.parse_expression()?;
-        self.expect(smallvec![TokenKind::Paren(false)])?;
+    let struct_expr = StructLiteralExpression::builder()
+      .path(path)
+      .fields(fields)
+      .build();
 
-        Ok(expr)
-      },
-    })
+    Ok(
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::StructLiteral(struct_expr))
+        .build(),
+    )
   }
 
-  fn parse_term(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
-    let mut lhs = self.parse_factor()?;
-
-    let expected = smallvec![TokenKind::Star, TokenKind::Slash,];
-    while self.peek(expected.clone()).is_some() {
-      let op_token = self.expect(expected.clone())?;
-      let rhs = self.parse_factor()?;
-
-      let lhs_loc = lhs.location().clone();
-      let rhs_loc = rhs.location().clone();
-      let combined = lhs_loc.merge(&rhs_loc).unwrap();
-
-      let lhs_heaped = self.ast.expr_arena().alloc(lhs);
-      let rhs_heaped = self.ast.expr_arena().alloc(rhs);
+  /// Parses the condition of an `if` or `while`, where a `{` always starts the
+  /// body.
+  fn parse_condition(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let struct_literals = std::mem::replace(&mut self.struct_literals, false);
+    let condition = self.parse_expression();
+    self.struct_literals = struct_literals;
+    condition
+  }
 
-      switch!(op_token.kind() => {
-        TokenKind::Star => {
-          let expr = MultiplyExpression::builder()
-            .left(lhs_heaped)
-            .right(rhs_heaped)
-            .build();
-          lhs = Expression::builder()
-            .location(combined.clone())
-            .kind(ExpressionKind::Binary(BinaryExpression::Multiply(expr)))
-            .build();
-        },
-        TokenKind::Slash => {
-          let expr = DivideExpression::builder()
-            .left(lhs_heaped)
-            .right(rhs_heaped)
-            .build();
-          lhs = Expression::builder()
-            .location(combined.clone())
-            .kind(ExpressionKind::Binary(BinaryExpression::Divide(expr)))
-            .build();
-        }
-      });
-    }
+  fn make_unary(
+    &mut self,
+    operator: UnaryOperator,
+    operand: Expression<'ast, 'src>,
+    location: Location<'src>,
+  ) -> Expression<'ast, 'src> {
+    let operand_heaped = self.ast.expr_arena().alloc(operand);
+    let unary_expr = UnaryExpression::builder()
+      .operator(operator)
+      .operand(operand_heaped)
+      .build();
 
-    Ok(lhs)
+    Expression::builder()
+      .location(location)
+      .kind(ExpressionKind::Unary(unary_expr))
+      .build()
   }
 
-  fn parse_expression(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
-    let mut lhs = self.parse_term()?;
+  fn parse_unary(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let op_token = self.expect(unary_operators())?;
 
-    let expected = smallvec![TokenKind::Plus, TokenKind::Minus,];
-    if self.peek(expected.clone()).is_some() {
-      let op_token = self.expect(expected)?;
-      let rhs = self.parse_term()?;
+    // Unary operators bind tighter than any binary operator but looser than
+    // postfix operators: `-a * b` is `(-a) * b` and `*f(x)` is `*(f(x))`.
+    let operand = self.parse_factor()?;
+    let location = op_token.location().merge(operand.location()).unwrap();
 
-      let lhs_loc = lhs.location().clone();
-      let rhs_loc = rhs.location().clone();
-      let combined = lhs_loc.merge(&rhs_loc).unwrap();
+    let operator = switch!(op_token.kind() => {
+      TokenKind::Minus => UnaryOperator::Negate,
+      TokenKind::Bang => UnaryOperator::Not,
+      TokenKind::Ampersand | TokenKind::LogicalAnd => UnaryOperator::AddressOf,
+      TokenKind::Star => UnaryOperator::Deref,
+    });
 
-      let lhs_heaped = self.ast.expr_arena().alloc(lhs);
-      let rhs_heaped = self.ast.expr_arena().alloc(rhs);
+    let expr = self.make_unary(operator, operand, location.clone());
 
-      switch!(op_token.kind() => {
-        TokenKind::Plus => {
-          let expr = AddExpression::builder()
-            .left(lhs_heaped)
-            .right(rhs_heaped)
-            .build();
-
-          lhs = Expression::builder()
-            .location(combined.clone())
-            .kind(ExpressionKind::Binary(BinaryExpression::Add(expr)))
-            .build();
-        },
-        TokenKind::Minus => {
-          let expr = SubtractExpression::builder()
-            .left(lhs_heaped)
-            .right(rhs_heaped)
-            .build();
-
-          lhs = Expression::builder()
-            .location(combined.clone())
-            .kind(ExpressionKind::Binary(BinaryExpression::Subtract(expr)))
-            .build();
-        }
-      });
+    // `&&x` is lexed as a single `LogicalAnd` token but means `&(&x)`.
+    if *op_token.kind() == TokenKind::LogicalAnd {
+      return Ok(self.make_unary(UnaryOperator::AddressOf, expr, location));
     }
 
-    Ok(lhs)
+    Ok(expr)
   }
 
-  fn parse_variable_stmt(&mut self) -> Result<VariableStatement<'ast, 'src>, ParseErrorMOO<'src>> {
-    let var_start = self.expect(smallvec![TokenKind::Var])?;
-    let var_name_tok = self.expect(smallvec![TokenKind::Identifier])?;
-
-    let type_ = if self.peek(smallvec![TokenKind::Colon]).is_some() {
-      self.expect(smallvec![TokenKind::Colon])?;
-      let (ty, ty_loc) = self.parse_type()?;
-      (ty, ty_loc)
-    } else {
-      (Type::Infer, var_start.location().clone())
-    };
-
-    self.expect(smallvec![TokenKind::Assign])?;
+  fn parse_if(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let if_token = self.expect(smallvec![TokenKind::If])?;
+    let condition = self.parse_condition()?;
+    let (then_block, then_location) = self.parse_block()?;
+
+    let mut location = if_token.location().merge(&then_location).unwrap();
+    let mut else_branch = None;
+    if self.peek(smallvec![TokenKind::Else]).is_some() {
+      self.expect(smallvec![TokenKind::Else])?;
+
+      let else_expr = if self.peek(smallvec![TokenKind::If]).is_some() {
+        self.parse_if()?
+      } else {
+        let (else_block, else_location) = self.parse_block()?;
+        Expression::builder()
+          .location(else_location)
+          .kind(ExpressionKind::Block(else_block))
+          .build()
+      };
 
-    let initializer = self.parse_expression()?;
-    self.expect(smallvec![TokenKind::Semicolon])?;
+      location = location.merge(else_expr.location()).unwrap();
+      else_branch = Some(&mut *self.ast.expr_arena().alloc(else_expr));
+    }
 
-    let name_located = Located::builder()
-      .value(self.strintern.intern(var_name_tok.lexeme()))
-      .location(var_name_tok.location().clone())
+    let condition_heaped = self.ast.expr_arena().alloc(condition);
+    let if_expr = IfExpression::builder()
+      .condition(condition_heaped)
+      .then_block(then_block)
+      .else_branch(else_branch)
       .build();
 
-    let type_located = Located::builder().value(type_.0).location(type_.1).build();
-
     Ok(
-      VariableStatement::builder()
-        .name(name_located)
-        .initializer(initializer)
-        .type_(type_located)
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::If(if_expr))
         .build(),
     )
   }
 
-  fn parse_statement(&mut self) -> Result<Statement<'ast, 'src>, ParseErrorMOO<'src>> {
-    if self.peek(expected_expression()).is_some() {
-      let expr = self.parse_expression()?;
-      self.expect(smallvec![TokenKind::Semicolon])?;
+  fn parse_while(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let while_token = self.expect(smallvec![TokenKind::While])?;
+    let condition = self.parse_condition()?;
+    let (body, body_location) = self.parse_block()?;
 
-      return Ok(
-        Statement::builder()
-          .location(expr.location().clone())
-          .kind(StatementKind::Expression(expr))
-          .build(),
-      );
-    }
+    let condition_heaped = self.ast.expr_arena().alloc(condition);
+    let while_expr = WhileExpression::builder()
+      .condition(condition_heaped)
+      .body(body)
+      .build();
+
+    Ok(
+      Expression::builder()
+        .location(while_token.location().merge(&body_location).unwrap())
+        .kind(ExpressionKind::While(while_expr))
+        .build(),
+    )
+  }
 
-    let expected = smallvec![TokenKind::Var,];
+  fn parse_loop(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let loop_token = self.expect(smallvec![TokenKind::Loop])?;
+    let (body, body_location) = self.parse_block()?;
 
-    let stmt_start = match self.peek(expected.clone()) {
+    let loop_expr = LoopExpression::builder().body(body).build();
+
+    Ok(
+      Expression::builder()
+        .location(loop_token.location().merge(&body_location).unwrap())
+        .kind(ExpressionKind::Loop(loop_expr))
+        .build(),
+    )
+  }
+
+  fn parse_pattern(&mut self) -> Result<Pattern<'ast, 'src>, ParseErrorMOO<'src>> {
+    let expected = smallvec![
+      TokenKind::Underscore,
+      TokenKind::Identifier,
+      TokenKind::Integer,
+      TokenKind::Float,
+      TokenKind::String,
+      TokenKind::Char,
+      TokenKind::True,
+      TokenKind::False,
+      TokenKind::Minus,
+    ];
+    let kind = match self.peek(expected.clone()) {
       Some(kind) => kind,
       None => {
-        if let Err(error) = self.expect(expected.clone()) {
-          return Err(ParseErrorMOO(Either::Left(error)));
-        }
+        self.expect(expected)?;
         unreachable!("Expected error but got a valid token")
       }
     };
 
-    switch!(stmt_start => {
-      TokenKind::Var => {
-        let var_stmt = self.parse_variable_stmt()?;
-        let stmt_location = var_stmt.name().location().clone();
+    switch!(kind => {
+      TokenKind::Underscore => {
+        let underscore = self.expect(smallvec![TokenKind::Underscore])?;
         Ok(
-          Statement::builder()
-            .location(stmt_location)
-            .kind(StatementKind::Variable(var_stmt))
+          Pattern::builder()
+            .kind(PatternKind::Wildcard)
+            .location(underscore.location().clone())
             .build(),
         )
-      }
+      },
+      TokenKind::Integer
+      | TokenKind::Float
+      | TokenKind::String
+      | TokenKind::Char
+      | TokenKind::True
+      | TokenKind::False => {
+        let (literal, location) = self.parse_literal()?;
+        Ok(
+          Pattern::builder()
+            .kind(PatternKind::Literal(literal))
+            .location(location)
+            .build(),
+        )
+      },
+      TokenKind::Minus => {
+        let minus = self.expect(smallvec![TokenKind::Minus])?;
+        if self.peek(smallvec![TokenKind::Integer, TokenKind::Float]).is_none() {
+          self.expect(smallvec![TokenKind::Integer, TokenKind::Float])?;
+        }
+        let (literal, location) = self.parse_literal()?;
+
+        let negated = match literal {
+          LiteralExpression::Integer(int) => LiteralExpression::Integer(
+            IntegerExpression::builder()
+              .value(*int.value())
+              .negative(!*int.negative())
+              .suffix(*int.suffix())
+              .build(),
+          ),
+          LiteralExpression::Float(float) => LiteralExpression::Float(
+            FloatExpression::builder()
+              .value(-*float.value())
+              .suffix(*float.suffix())
+              .build(),
+          ),
+          _ => unreachable!("Only numeric literals can be negated"),
+        };
+
+        Ok(
+          Pattern::builder()
+            .kind(PatternKind::Literal(negated))
+            .location(minus.location().merge(&location).unwrap())
+            .build(),
+        )
+      },
+      TokenKind::Identifier => {
+        let path = self.parse_path()?;
+        let mut location = path.location();
+
+        // A lone name binds; anything qualified or with a payload names a
+        // variant, so unit variants are written `Color::Red`.
+        if path.is_single() && self.peek(smallvec![TokenKind::Paren(true)]).is_none() {
+          return Ok(
+            Pattern::builder()
+              .kind(PatternKind::Binding(path.name()))
+              .location(location)
+              .build(),
+          );
+        }
+
+        let mut pre_fields = SmallVec::<[Pattern<'ast, 'src>; MAX_INLINE_ARGS]>::new();
+        if self.peek(smallvec![TokenKind::Paren(true)]).is_some() {
+          self.expect(smallvec![TokenKind::Paren(true)])?;
+          while self.peek(smallvec![TokenKind::Paren(false)]).is_none() {
+            pre_fields.push(self.parse_pattern()?);
+            if self.peek(smallvec![TokenKind::Comma]).is_none() {
+              break;
+            }
+            self.expect(smallvec![TokenKind::Comma])?;
+          }
+          let close = self.expect(smallvec![TokenKind::Paren(false)])?;
+          location = location.merge(close.location()).unwrap();
+        }
+
+        let field_count = pre_fields.len();
+        let mut pre_fields = pre_fields.into_iter();
+        let fields = self
+          .ast
+          .pattern_arena()
+          .alloc_slice_with(field_count, |_| pre_fields.next().unwrap());
+
+        let variant = VariantPattern::builder()
+          .path(path)
+          .fields(&*fields)
+          .build();
+        Ok(
+          Pattern::builder()
+            .kind(PatternKind::Variant(variant))
+            .location(location)
+            .build(),
+        )
+      },
     })
   }
 
-  fn parse_block(&mut self) -> Result<BlockExpression<'ast, 'src>, ParseErrorMOO<'src>> {
-    let mut status = ParseStatus::Success;
-    let mut statements = Vec::new();
-    let mut errors = SmallVec::new();
+  fn parse_match(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let match_token = self.expect(smallvec![TokenKind::Match])?;
+    let scrutinee = self.parse_condition()?;
+    self.expect(smallvec![TokenKind::Brace(true)])?;
+    let struct_literals = std::mem::replace(&mut self.struct_literals, true);
 
-    let blk_start = self.expect(smallvec![TokenKind::Brace(true)])?;
+    let mut pre_arms = SmallVec::<[MatchArm<'ast, 'src>; MAX_INLINE_ARMS]>::new();
+    let result = loop {
+      if self.peek(smallvec![TokenKind::Brace(false)]).is_some() {
+        break Ok(());
+      }
 
-    let sync_points = smallvec![TokenKind::Semicolon, TokenKind::Brace(false),];
+      let pattern = match self.parse_pattern() {
+        Ok(pattern) => pattern,
+        Err(error) => break Err(error),
+      };
+      if let Err(error) = self.expect(smallvec![TokenKind::FatArrow]) {
+        break Err(error.into());
+      }
 
-    while self
-      .peek(smallvec![TokenKind::Brace(false), TokenKind::Eof])
-      .is_none()
-    {
-      match self.parse_statement() {
-        Ok(statement) => {
-          statements.push(statement);
+      let body = if self.peek(smallvec![TokenKind::Brace(true)]).is_some() {
+        match self.parse_block() {
+          Ok((block, location)) => Expression::builder()
+            .location(location)
+            .kind(ExpressionKind::Block(block))
+            .build(),
+          Err(error) => break Err(error),
         }
-        Err(error) => {
-          match error.0 {
-            Either::Left(parse_error) => {
-              errors.push(parse_error);
-              status = ParseStatus::Error;
-            }
-            Either::Right(multiple_errors) => {
-              errors.extend(multiple_errors);
-              status = ParseStatus::Error;
-            }
-          }
+      } else {
+        match self.parse_expression() {
+          Ok(body) => body,
+          Err(error) => break Err(error),
+        }
+      };
 
-          self.sync(sync_points.clone());
+      // Arms whose body ends in a brace do not need a separating comma.
+      let braced = matches!(
+        body.kind(),
+        ExpressionKind::Block(_)
+          | ExpressionKind::If(_)
+          | ExpressionKind::While(_)
+          | ExpressionKind::Loop(_)
+          | ExpressionKind::Match(_)
+      );
+      pre_arms.push(MatchArm::builder().pattern(pattern).body(body).build());
 
-          if self.peek(smallvec![TokenKind::Semicolon]).is_some() {
-            self.expect(smallvec![TokenKind::Semicolon])?;
-          }
-        }
+      if self.peek(smallvec![TokenKind::Comma]).is_some() {
+        self.lexer.next();
+      } else if !braced {
+        break Ok(());
       }
-    }
+    };
+    self.struct_literals = struct_literals;
+    result?;
 
-    let blk_end = self.expect(smallvec![TokenKind::Brace(false)])?;
-    let _location = blk_start.location().merge(blk_end.location()).unwrap();
+    let close = self.expect(smallvec![TokenKind::Brace(false)])?;
 
-    match status {
-      ParseStatus::Error => Err(ParseErrorMOO(Either::Right(errors))),
-      ParseStatus::Success => Ok(BlockExpression::builder().body(statements).build()),
-    }
+    let arm_count = pre_arms.len();
+    let mut pre_arms = pre_arms.into_iter();
+    let arms = self
+      .ast
+      .arm_arena()
+      .alloc_slice_with(arm_count, |_| pre_arms.next().unwrap());
+
+    let scrutinee_heaped = self.ast.expr_arena().alloc(scrutinee);
+    let match_expr = MatchExpression::builder()
+      .keyword(match_token.location().clone())
+      .scrutinee(scrutinee_heaped)
+      .arms(arms)
+      .build();
+
+    Ok(
+      Expression::builder()
+        .location(match_token.location().merge(close.location()).unwrap())
+        .kind(ExpressionKind::Match(match_expr))
+        .build(),
+    )
   }
 
-  fn parse_function(&mut self) -> Result<Function<'ast, 'src>, ParseErrorMOO<'src>> {
-    self.expect(smallvec![TokenKind::Func])?;
+  fn parse_block_like(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let expected = block_like_expression();
+    let kind = match self.peek(expected.clone()) {
+      Some(kind) => kind,
+      None => {
+        self.expect(expected)?;
+        unreachable!("Expected error but got a valid token")
+      }
+    };
 
-    let name_token = self.expect(smallvec![TokenKind::Identifier])?;
+    switch!(kind => {
+      TokenKind::If => self.parse_if(),
+      TokenKind::While => self.parse_while(),
+      TokenKind::Loop => self.parse_loop(),
+      TokenKind::Match => self.parse_match(),
+    })
+  }
 
+  fn parse_call(
+    &mut self,
+    callee: Expression<'ast, 'src>,
+  ) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
     self.expect(smallvec![TokenKind::Paren(true)])?;
-    let mut pre_params = SmallVec::<[FunctionParam<'src>; MAX_INLINE_PARAMS]>::new();
-    while self.peek(smallvec![TokenKind::Identifier]).is_some() {
-      let param = self.parse_tn_pair()?;
-      let param_location = param.location().clone();
-      let param_located = Located::builder()
-        .value(param)
-        .location(param_location)
-        .build();
-      pre_params.push(param_located.into());
-      if self.peek(smallvec![TokenKind::Comma]).is_some() {
-        self.expect(smallvec![TokenKind::Comma])?;
+
+    let mut pre_args = SmallVec::<[Expression<'ast, 'src>; MAX_INLINE_ARGS]>::new();
+    while self.peek(smallvec![TokenKind::Paren(false)]).is_none() {
+      pre_args.push(self.parse_expression()?);
+      if self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
       }
+      self.expect(smallvec![TokenKind::Comma])?;
     }
 
-    let raw_params = self
+    let close = self.expect(smallvec![TokenKind::Paren(false)])?;
+    let location = callee.location().merge(close.location()).unwrap();
+
+    let arg_count = pre_args.len();
+    let mut pre_args = pre_args.into_iter();
+    let args = self
       .ast
-      .param_arena()
-      .as_untyped()
-      .alloc_slice_with(pre_params.len(), |_| MaybeUninit::uninit());
-    for (i, param) in pre_params.into_iter().enumerate() {
-      raw_params[i] = MaybeUninit::new(param);
-    }
-    let final_params: &'ast [FunctionParam<'src>] = unsafe { std::mem::transmute(raw_params) };
+      .expr_arena()
+      .alloc_slice_with(arg_count, |_| pre_args.next().unwrap());
 
-    self.expect(smallvec![TokenKind::Paren(false)])?;
-    self.expect(smallvec![TokenKind::Colon])?;
-    let (return_type, ret_loc) = self.parse_type()?;
+    let callee_heaped = self.ast.expr_arena().alloc(callee);
+    let call_expr = CallExpression::builder()
+      .callee(callee_heaped)
+      .args(args)
+      .build();
 
-    let mut block = None;
-    if self.peek(smallvec![TokenKind::Brace(true)]).is_some() {
-      let block_expr = self.parse_block()?;
-      block = Some(block_expr);
-    } else {
-      self.expect(smallvec![TokenKind::Semicolon])?;
-    }
+    Ok(
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::Call(call_expr))
+        .build(),
+    )
+  }
 
-    let name_located = Located::builder()
-      .value(self.strintern.intern(name_token.lexeme()))
-      .location(name_token.location().clone())
+  fn parse_field(
+    &mut self,
+    object: Expression<'ast, 'src>,
+  ) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Dot])?;
+    let field_token = self.expect(smallvec![TokenKind::Identifier])?;
+    let location = object.location().merge(field_token.location()).unwrap();
+
+    let field_located = Located::builder()
+      .value(self.intern_identifier(&field_token))
+      .location(field_token.location().clone())
       .build();
-
-    let return_type_located = Located::builder()
-      .value(return_type)
-      .location(ret_loc)
+    let object_heaped = self.ast.expr_arena().alloc(object);
+    let field_expr = FieldExpression::builder()
+      .object(object_heaped)
+      .field(field_located)
       .build();
 
     Ok(
-      Function::builder()
-        .name(name_located)
-        .params(final_params)
-        .block(block)
-        .return_type(return_type_located)
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::Field(field_expr))
         .build(),
     )
   }
 
-  pub fn parse(&mut self) -> Result<(), ()> {
-    self.lexer.reset();
+  fn parse_index(
+    &mut self,
+    object: Expression<'ast, 'src>,
+  ) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Bracket(true)])?;
+    let struct_literals = std::mem::replace(&mut self.struct_literals, true);
+    let index = self.parse_expression();
+    self.struct_literals = struct_literals;
+    let index = index?;
+    let close = self.expect(smallvec![TokenKind::Bracket(false)])?;
+    let location = object.location().merge(close.location()).unwrap();
+
+    let object_heaped = self.ast.expr_arena().alloc(object);
+    let index_heaped = self.ast.expr_arena().alloc(index);
+    let index_expr = IndexExpression::builder()
+      .object(object_heaped)
+      .index(index_heaped)
+      .build();
 
-    let mut status = ParseStatus::Success;
-    let expected = smallvec![TokenKind::Func, TokenKind::Eof];
+    Ok(
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::Index(index_expr))
+        .build(),
+    )
+  }
 
-    loop {
-      let kind_tag = match self.peek(expected.clone()) {
-        Some(kind) => kind,
-        None => {
-          if let Err(error) = self.expect(expected.clone()) {
-            status = ParseStatus::Error;
-            self.handle_parse_error(error.into());
-            self.sync(expected.clone());
-            continue;
-          }
-          unreachable!("Expected error but got a valid token")
-        }
-      };
+  fn parse_array(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let open = self.expect(smallvec![TokenKind::Bracket(true)])?;
+    let struct_literals = std::mem::replace(&mut self.struct_literals, true);
+    let array = self.parse_array_elements();
+    self.struct_literals = struct_literals;
+    let array = array?;
+    let close = self.expect(smallvec![TokenKind::Bracket(false)])?;
 
-      if kind_tag == TokenKind::Eof {
-        break;
+    Ok(
+      Expression::builder()
+        .location(open.location().merge(close.location()).unwrap())
+        .kind(ExpressionKind::Array(array))
+        .build(),
+    )
+  }
+
+  fn parse_array_elements(&mut self) -> Result<ArrayExpression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let mut pre_elements = SmallVec::<[Expression<'ast, 'src>; MAX_INLINE_ARGS]>::new();
+    if self.peek(smallvec![TokenKind::Bracket(false)]).is_none() {
+      let first = self.parse_expression()?;
+
+      if self.peek(smallvec![TokenKind::Semicolon]).is_some() {
+        self.expect(smallvec![TokenKind::Semicolon])?;
+        let count = self.parse_expression()?;
+
+        let value_heaped = self.ast.expr_arena().alloc(first);
+        let count_heaped = self.ast.expr_arena().alloc(count);
+        return Ok(ArrayExpression::Repeat(
+          ArrayRepeatExpression::builder()
+            .value(value_heaped)
+            .count(count_heaped)
+            .build(),
+        ));
       }
 
-      switch!(kind_tag => {
-        TokenKind::Func => {
-          let res = self.parse_function();
-          match res {
-            Ok(func) => {
-              self.ast.funcs_mut().push(func);
-            }
-            Err(error) => {
-              self.handle_parse_error(error);
-              status = ParseStatus::Error;
-              self.sync(expected.clone());
-              continue;
-            }
-          }
+      pre_elements.push(first);
+      while self.peek(smallvec![TokenKind::Comma]).is_some() {
+        self.expect(smallvec![TokenKind::Comma])?;
+        if self.peek(smallvec![TokenKind::Bracket(false)]).is_some() {
+          break;
         }
-      })
+        pre_elements.push(self.parse_expression()?);
+      }
     }
 
-    match status {
-      ParseStatus::Success => Ok(()),
-      ParseStatus::Error => Err(()),
+    let element_count = pre_elements.len();
+    let mut pre_elements = pre_elements.into_iter();
+    let elements = self
+      .ast
+      .expr_arena()
+      .alloc_slice_with(element_count, |_| pre_elements.next().unwrap());
+
+    Ok(ArrayExpression::List(
+      ArrayListExpression::builder().elements(elements).build(),
+    ))
+  }
+
+  fn parse_postfix(
+    &mut self,
+    mut expr: Expression<'ast, 'src>,
+  ) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    while let Some(kind) = self.peek(smallvec![
+      TokenKind::Paren(true),
+      TokenKind::Bracket(true),
+      TokenKind::Dot
+    ]) {
+      expr = switch!(kind => {
+        TokenKind::Paren(true) => self.parse_call(expr)?,
+        TokenKind::Bracket(true) => self.parse_index(expr)?,
+        TokenKind::Dot => self.parse_field(expr)?,
+      });
+    }
+
+    Ok(expr)
+  }
+
+  fn parse_factor(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    if self.peek(unary_operators()).is_some() {
+      return self.parse_unary();
+    }
+
+    let primary = self.parse_primary()?;
+    self.parse_postfix(primary)
+  }
+
+  fn parse_literal(&mut self) -> Result<(LiteralExpression, Location<'src>), ParseErrorMOO<'src>> {
+    let token = self.expect(smallvec![
+      TokenKind::Integer,
+      TokenKind::Float,
+      TokenKind::String,
+      TokenKind::Char,
+      TokenKind::True,
+      TokenKind::False,
+    ])?;
+
+    let literal = match token.data() {
+      Some(TokenData::Integer(value, suffix)) => LiteralExpression::Integer(
+        IntegerExpression::builder()
+          .value(*value)
+          .suffix(*suffix)
+          .build(),
+      ),
+      Some(TokenData::Float(value, suffix, warning)) => {
+        if let Some(kind) = warning {
+          let type_name = suffix.map_or("f64", |suffix| suffix.name());
+          let warning = FloatWarning::new(
+            token.lexeme(),
+            token.location().clone(),
+            *kind,
+            type_name,
+            *value,
+          );
+          self.sink.report(warning.report()).unwrap();
+        }
+        LiteralExpression::Float(
+          FloatExpression::builder()
+            .value(*value)
+            .suffix(*suffix)
+            .build(),
+        )
+      }
+      Some(TokenData::String(value)) => LiteralExpression::String(
+        StringExpression::builder()
+          .value(self.strintern.intern(value))
+          .build(),
+      ),
+      Some(TokenData::Char(value)) => {
+        LiteralExpression::Char(CharExpression::builder().value(*value).build())
+      }
+      None => {
+        let value = *token.kind() == TokenKind::True;
+        LiteralExpression::Bool(BoolExpression::builder().value(value).build())
+      }
+      _ => unreachable!("Literal token missing data"),
+    };
+
+    Ok((literal, token.location().clone()))
+  }
+
+  fn parse_primary(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let expected = expected_expression();
+    let expr_type = match self.peek(expected.clone()) {
+      Some(kind) => kind,
+      None => {
+        self.expect(expected.clone())?;
+        unreachable!("Expected error but got a valid token")
+      }
+    };
+
+    switch!(expr_type => {
+      TokenKind::Integer
+      | TokenKind::Float
+      | TokenKind::String
+      | TokenKind::Char
+      | TokenKind::True
+      | TokenKind::False => {
+        let (literal, location) = self.parse_literal()?;
+
+        Ok(
+          Expression::builder()
+            .location(location)
+            .kind(ExpressionKind::Literal(literal))
+            .build(),
+        )
+      },
+      TokenKind::Identifier => Ok(self.parse_identifier()?),
+      TokenKind::Bracket(true) => self.parse_array(),
+      TokenKind::If | TokenKind::While | TokenKind::Loop | TokenKind::Match => {
+        self.parse_block_like()
+      },
+      TokenKind::Paren(true) => {
+        self.expect(smallvec![TokenKind::Paren(true)])?;
+        let struct_literals = std::mem::replace(&mut self.struct_literals, true);
+        let expr = self.parse_expression();
+        self.struct_literals = struct_literals;
+        let expr = expr?;
+        self.expect(smallvec![TokenKind::Paren(false)])?;
+
+        Ok(expr)
+      },
+    })
+  }
+
+  /// `as` binds looser than unary operators but tighter than any binary
+  /// operator: `-x as i64 * y` is `((-x) as i64) * y`.
+  fn parse_cast(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let mut expr = self.parse_factor()?;
+
+    while self.peek(smallvec![TokenKind::As]).is_some() {
+      self.expect(smallvec![TokenKind::As])?;
+      let (ty, type_location) = self.parse_type_in(true)?;
+      let location = expr.location().merge(&type_location).unwrap();
+
+      let value_heaped = self.ast.expr_arena().alloc(expr);
+      let cast_expr = CastExpression::builder()
+        .value(value_heaped)
+        .type_(Located::builder().value(ty).location(type_location).build())
+        .build();
+
+      expr = Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::Cast(cast_expr))
+        .build();
     }
+
+    Ok(expr)
+  }
+
+  fn parse_binary(
+    &mut self,
+    min_precedence: u8,
+  ) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let mut lhs = self.parse_cast()?;
+
+    while let Some((operator, precedence)) = binary_operator(*self.lexer.peek().kind()) {
+      if precedence < min_precedence {
+        break;
+      }
+      self.lexer.next();
+
+      // All binary operators are left-associative, so the right-hand side may
+      // only contain operators that bind strictly tighter.
+      let rhs = self.parse_binary(precedence + 1)?;
+
+      let combined = lhs.location().merge(rhs.location()).unwrap();
+      let lhs_heaped = self.ast.expr_arena().alloc(lhs);
+      let rhs_heaped = self.ast.expr_arena().alloc(rhs);
+
+      lhs = Expression::builder()
+        .location(combined)
+        .kind(ExpressionKind::Binary(BinaryExpression::new(
+          operator, lhs_heaped, rhs_heaped,
+        )))
+        .build();
+    }
+
+    Ok(lhs)
+  }
+
+  fn parse_expression(&mut self) -> Result<Expression<'ast, 'src>, ParseErrorMOO<'src>> {
+    let target = self.parse_binary(0)?;
+
+    let Some(operator) = assign_operator(*self.lexer.peek().kind()) else {
+      return Ok(target);
+    };
+    self.lexer.next();
+
+    if !is_assignable(&target) {
+      let error = AssignTargetError::new(target.location().clone());
+      return Err(ParseError::AssignTarget(error).into());
+    }
+
+    // Assignment is right-associative: `a = b = c` is `a = (b = c)`.
+    let value = self.parse_expression()?;
+    let location = target.location().merge(value.location()).unwrap();
+
+    let target_heaped = self.ast.expr_arena().alloc(target);
+    let value_heaped = self.ast.expr_arena().alloc(value);
+    let assign_expr = AssignExpression::builder()
+      .operator(operator)
+      .target(target_heaped)
+      .value(value_heaped)
+      .build();
+
+    Ok(
+      Expression::builder()
+        .location(location)
+        .kind(ExpressionKind::Assign(assign_expr))
+        .build(),
+    )
+  }
+
+  fn parse_variable_stmt(&mut self) -> Result<VariableStatement<'ast, 'src>, ParseErrorMOO<'src>> {
+    let var_start = self.expect(smallvec![TokenKind::Var])?;
+    let var_name_tok = self.expect(smallvec![TokenKind::Identifier])?;
+
+    let type_ = if self.peek(smallvec![TokenKind::Colon]).is_some() {
+      self.expect(smallvec![TokenKind::Colon])?;
+      let (ty, ty_loc) = self.parse_type()?;
+      (ty, ty_loc)
+    } else {
+      (Type::Infer, var_start.location().clone())
+    };
+
+    self.expect(smallvec![TokenKind::Assign])?;
+
+    let initializer = self.parse_expression()?;
+    self.expect(smallvec![TokenKind::Semicolon])?;
+
+    let name_located = Located::builder()
+      .value(self.intern_identifier(&var_name_tok))
+      .location(var_name_tok.location().clone())
+      .build();
+
+    let type_located = Located::builder().value(type_.0).location(type_.1).build();
+
+    Ok(
+      VariableStatement::builder()
+        .name(name_located)
+        .initializer(initializer)
+        .type_(type_located)
+        .build(),
+    )
+  }
+
+  fn parse_return_stmt(
+    &mut self,
+  ) -> Result<(ReturnStatement<'ast, 'src>, Location<'src>), ParseErrorMOO<'src>> {
+    let return_token = self.expect(smallvec![TokenKind::Return])?;
+
+    let value = if self.peek(smallvec![TokenKind::Semicolon]).is_none() {
+      Some(self.parse_expression()?)
+    } else {
+      None
+    };
+    let semicolon = self.expect(smallvec![TokenKind::Semicolon])?;
+
+    let location = return_token.location().merge(semicolon.location()).unwrap();
+    Ok((ReturnStatement::builder().value(value).build(), location))
+  }
+
+  fn parse_break_stmt(
+    &mut self,
+  ) -> Result<(BreakStatement<'ast, 'src>, Location<'src>), ParseErrorMOO<'src>> {
+    let break_token = self.expect(smallvec![TokenKind::Break])?;
+
+    let value = if self.peek(smallvec![TokenKind::Semicolon]).is_none() {
+      Some(self.parse_expression()?)
+    } else {
+      None
+    };
+    let semicolon = self.expect(smallvec![TokenKind::Semicolon])?;
+
+    let location = break_token.location().merge(semicolon.location()).unwrap();
+    Ok((BreakStatement::builder().value(value).build(), location))
+  }
+
+  /// Parses a statement, or the trailing expression of a block that is not
+  /// terminated by a semicolon and therefore becomes the block's value.
+  fn parse_statement(
+    &mut self,
+  ) -> Result<Either<Statement<'ast, 'src>, Expression<'ast, 'src>>, ParseErrorMOO<'src>> {
+    if self.peek(expected_expression()).is_some() {
+      // Block-like expressions end at their closing brace in statement position,
+      // so `if c { } *p = 1;` is two statements rather than a multiplication.
+      let block_like = self.peek(block_like_expression()).is_some();
+      let expr = if block_like {
+        self.parse_block_like()?
+      } else {
+        self.parse_expression()?
+      };
+
+      if self.peek(smallvec![TokenKind::Brace(false)]).is_some() {
+        return Ok(Either::Right(expr));
+      }
+      if !block_like || self.peek(smallvec![TokenKind::Semicolon]).is_some() {
+        self.expect(smallvec![TokenKind::Semicolon])?;
+      }
+
+      return Ok(Either::Left(
+        Statement::builder()
+          .location(expr.location().clone())
+          .kind(StatementKind::Expression(expr))
+          .build(),
+      ));
+    }
+
+    let expected = smallvec![
+      TokenKind::Var,
+      TokenKind::Return,
+      TokenKind::Break,
+      TokenKind::Continue,
+    ];
+
+    let stmt_start = match self.peek(expected.clone()) {
+      Some(kind) => kind,
+      None => {
+        if let Err(error) = self.expect(expected.clone()) {
+          return Err(ParseErrorMOO(Either::Left(error)));
+        }
+        unreachable!("Expected error but got a valid token")
+      }
+    };
+
+    let statement = switch!(stmt_start => {
+      TokenKind::Var => {
+        let var_stmt = self.parse_variable_stmt()?;
+        let stmt_location = var_stmt.name().location().clone();
+        Statement::builder()
+          .location(stmt_location)
+          .kind(StatementKind::Variable(var_stmt))
+          .build()
+      },
+      TokenKind::Return => {
+        let (return_stmt, stmt_location) = self.parse_return_stmt()?;
+        Statement::builder()
+          .location(stmt_location)
+          .kind(StatementKind::Return(return_stmt))
+          .build()
+      },
+      TokenKind::Break => {
+        let (break_stmt, stmt_location) = self.parse_break_stmt()?;
+        Statement::builder()
+          .location(stmt_location)
+          .kind(StatementKind::Break(break_stmt))
+          .build()
+      },
+      TokenKind::Continue => {
+        let continue_token = self.expect(smallvec![TokenKind::Continue])?;
+        let semicolon = self.expect(smallvec![TokenKind::Semicolon])?;
+        Statement::builder()
+          .location(continue_token.location().merge(semicolon.location()).unwrap())
+          .kind(StatementKind::Continue)
+          .build()
+      }
+    });
+
+    Ok(Either::Left(statement))
+  }
+
+  fn parse_block(
+    &mut self,
+  ) -> Result<(BlockExpression<'ast, 'src>, Location<'src>), ParseErrorMOO<'src>> {
+    let mut status = ParseStatus::Success;
+    let mut statements = Vec::new();
+    let mut tail = None;
+    let mut errors = SmallVec::new();
+
+    let blk_start = self.expect(smallvec![TokenKind::Brace(true)])?;
+    let struct_literals = std::mem::replace(&mut self.struct_literals, true);
+
+    let sync_points = smallvec![TokenKind::Semicolon, TokenKind::Brace(false),];
+
+    while self
+      .peek(smallvec![TokenKind::Brace(false), TokenKind::Eof])
+      .is_none()
+    {
+      match self.parse_statement() {
+        Ok(Either::Left(statement)) => {
+          statements.push(statement);
+        }
+        Ok(Either::Right(expr)) => {
+          tail = Some(&mut *self.ast.expr_arena().alloc(expr));
+        }
+        Err(error) => {
+          match error.0 {
+            Either::Left(parse_error) => {
+              errors.push(parse_error);
+              status = ParseStatus::Error;
+            }
+            Either::Right(multiple_errors) => {
+              errors.extend(multiple_errors);
+              status = ParseStatus::Error;
+            }
+          }
+
+          self.sync(sync_points.clone());
+
+          if self.peek(smallvec![TokenKind::Semicolon]).is_some() {
+            self.expect(smallvec![TokenKind::Semicolon])?;
+          }
+        }
+      }
+    }
+
+    self.struct_literals = struct_literals;
+    let blk_end = self.expect(smallvec![TokenKind::Brace(false)])?;
+    let location = blk_start.location().merge(blk_end.location()).unwrap();
+
+    match status {
+      ParseStatus::Error => Err(ParseErrorMOO(Either::Right(errors))),
+      ParseStatus::Success => Ok((
+        BlockExpression::builder()
+          .body(statements)
+          .tail(tail)
+          .build(),
+        location,
+      )),
+    }
+  }
+
+  fn parse_extern(&mut self) -> Result<Function<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Extern])?;
+    let abi_token = self.expect(smallvec![TokenKind::String])?;
+
+    let abi = match abi_token.data() {
+      Some(TokenData::String(value)) => value,
+      _ => unreachable!("String token missing data"),
+    };
+    if abi != "C" {
+      let error = ExternFunctionError::new(
+        abi_token.location().clone(),
+        ExternFunctionErrorKind::UnsupportedAbi,
+      );
+      return Err(ParseError::ExternFunction(error).into());
+    }
+
+    let abi_located = Located::builder()
+      .value(self.strintern.intern(abi))
+      .location(abi_token.location().clone())
+      .build();
+    self.parse_function(Some(abi_located))
+  }
+
+  fn parse_function(
+    &mut self,
+    abi: Option<Located<'src, Entry>>,
+  ) -> Result<Function<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Func])?;
+
+    let name_token = self.expect(smallvec![TokenKind::Identifier])?;
+    let generics = self.parse_generic_params()?;
+
+    self.expect(smallvec![TokenKind::Paren(true)])?;
+    let mut pre_params = SmallVec::<[FunctionParam<'src>; MAX_INLINE_PARAMS]>::new();
+    let mut variadic = false;
+    while self.peek(smallvec![TokenKind::Identifier, TokenKind::Ellipsis]).is_some() {
+      if self.peek(smallvec![TokenKind::Ellipsis]).is_some() {
+        let ellipsis = self.expect(smallvec![TokenKind::Ellipsis])?;
+        if abi.is_none() {
+          let error = ExternFunctionError::new(
+            ellipsis.location().clone(),
+            ExternFunctionErrorKind::VariadicDefinition,
+          );
+          return Err(ParseError::ExternFunction(error).into());
+        }
+        variadic = true;
+        break;
+      }
+
+      let param = self.parse_tn_pair()?;
+      let param_location = param.location().clone();
+      let param_located = Located::builder()
+        .value(param)
+        .location(param_location)
+        .build();
+      pre_params.push(param_located.into());
+      if self.peek(smallvec![TokenKind::Comma]).is_some() {
+        self.expect(smallvec![TokenKind::Comma])?;
+      }
+    }
+
+    let param_count = pre_params.len();
+    let mut pre_params = pre_params.into_iter();
+    let final_params: &'ast [FunctionParam<'src>] = self
+      .ast
+      .param_arena()
+      .alloc_slice_with(param_count, |_| pre_params.next().unwrap());
+
+    self.expect(smallvec![TokenKind::Paren(false)])?;
+    self.expect(smallvec![TokenKind::Colon])?;
+    let (return_type, ret_loc) = self.parse_type()?;
+
+    let mut block = None;
+    if self.peek(smallvec![TokenKind::Brace(true)]).is_some() {
+      let (block_expr, block_location) = self.parse_block()?;
+      if abi.is_some() {
+        let error = ExternFunctionError::new(block_location, ExternFunctionErrorKind::Body);
+        return Err(ParseError::ExternFunction(error).into());
+      }
+      block = Some(block_expr);
+    } else {
+      self.expect(smallvec![TokenKind::Semicolon])?;
+    }
+
+    let name_located = Located::builder()
+      .value(self.intern_identifier(&name_token))
+      .location(name_token.location().clone())
+      .build();
+
+    let return_type_located = Located::builder()
+      .value(return_type)
+      .location(ret_loc)
+      .build();
+
+    Ok(
+      Function::builder()
+        .name(name_located)
+        .generics(generics)
+        .params(final_params)
+        .block(block)
+        .return_type(return_type_located)
+        .abi(abi)
+        .variadic(variadic)
+        .build(),
+    )
+  }
+
+  fn parse_struct(&mut self) -> Result<Struct<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Struct])?;
+
+    let name_token = self.expect(smallvec![TokenKind::Identifier])?;
+    let generics = self.parse_generic_params()?;
+
+    self.expect(smallvec![TokenKind::Brace(true)])?;
+    let mut pre_fields = SmallVec::<[StructField<'src>; MAX_INLINE_FIELDS]>::new();
+    while self.peek(smallvec![TokenKind::Identifier]).is_some() {
+      let field = self.parse_tn_pair()?;
+      let field_location = field.location().clone();
+      let field_located = Located::builder()
+        .value(field)
+        .location(field_location)
+        .build();
+      pre_fields.push(field_located.into());
+      if self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::Comma])?;
+    }
+    self.expect(smallvec![TokenKind::Brace(false)])?;
+
+    let field_count = pre_fields.len();
+    let mut pre_fields = pre_fields.into_iter();
+    let fields = self
+      .ast
+      .field_arena()
+      .alloc_slice_with(field_count, |_| pre_fields.next().unwrap());
+
+    let name_located = Located::builder()
+      .value(self.intern_identifier(&name_token))
+      .location(name_token.location().clone())
+      .build();
+
+    Ok(
+      Struct::builder()
+        .name(name_located)
+        .generics(generics)
+        .fields(&*fields)
+        .build(),
+    )
+  }
+
+  fn parse_enum(&mut self) -> Result<Enum<'ast, 'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Enum])?;
+
+    let name_token = self.expect(smallvec![TokenKind::Identifier])?;
+
+    self.expect(smallvec![TokenKind::Brace(true)])?;
+    let mut pre_variants = SmallVec::<[EnumVariant<'ast, 'src>; MAX_INLINE_VARIANTS]>::new();
+    while self.peek(smallvec![TokenKind::Identifier]).is_some() {
+      let variant_token = self.expect(smallvec![TokenKind::Identifier])?;
+
+      let mut pre_payload = SmallVec::<[Located<'src, Type<'src>>; MAX_INLINE_PARAMS]>::new();
+      if self.peek(smallvec![TokenKind::Paren(true)]).is_some() {
+        self.expect(smallvec![TokenKind::Paren(true)])?;
+        while self.peek(smallvec![TokenKind::Paren(false)]).is_none() {
+          let (ty, location) = self.parse_type()?;
+          pre_payload.push(Located::builder().value(ty).location(location).build());
+          if self.peek(smallvec![TokenKind::Comma]).is_none() {
+            break;
+          }
+          self.expect(smallvec![TokenKind::Comma])?;
+        }
+        self.expect(smallvec![TokenKind::Paren(false)])?;
+      }
+
+      let payload_count = pre_payload.len();
+      let mut pre_payload = pre_payload.into_iter();
+      let payload = self
+        .ast
+        .payload_arena()
+        .alloc_slice_with(payload_count, |_| pre_payload.next().unwrap());
+
+      let variant_name = Located::builder()
+        .value(self.intern_identifier(&variant_token))
+        .location(variant_token.location().clone())
+        .build();
+      pre_variants.push(
+        EnumVariant::builder()
+          .name(variant_name)
+          .payload(&*payload)
+          .build(),
+      );
+
+      if self.peek(smallvec![TokenKind::Comma]).is_none() {
+        break;
+      }
+      self.expect(smallvec![TokenKind::Comma])?;
+    }
+    self.expect(smallvec![TokenKind::Brace(false)])?;
+
+    let variant_count = pre_variants.len();
+    let mut pre_variants = pre_variants.into_iter();
+    let variants = self
+      .ast
+      .variant_arena()
+      .alloc_slice_with(variant_count, |_| pre_variants.next().unwrap());
+
+    let name_located = Located::builder()
+      .value(self.intern_identifier(&name_token))
+      .location(name_token.location().clone())
+      .build();
+
+    Ok(
+      Enum::builder()
+        .name(name_located)
+        .variants(&*variants)
+        .build(),
+    )
+  }
+
+  fn parse_global(&mut self) -> Result<Global<'ast, 'src>, ParseErrorMOO<'src>> {
+    let keyword = self.expect(smallvec![TokenKind::Const, TokenKind::Var])?;
+    let kind = switch!(*keyword.kind() => {
+      TokenKind::Const => GlobalKind::Const,
+      TokenKind::Var => GlobalKind::Var,
+    });
+
+    let name_token = self.expect(smallvec![TokenKind::Identifier])?;
+    self.expect(smallvec![TokenKind::Colon])?;
+    let (type_, type_location) = self.parse_type()?;
+    self.expect(smallvec![TokenKind::Assign])?;
+    let initializer = self.parse_expression()?;
+    self.expect(smallvec![TokenKind::Semicolon])?;
+
+    let name_located = Located::builder()
+      .value(self.intern_identifier(&name_token))
+      .location(name_token.location().clone())
+      .build();
+    let type_located = Located::builder()
+      .value(type_)
+      .location(type_location)
+      .build();
+
+    Ok(
+      Global::builder()
+        .kind(kind)
+        .name(name_located)
+        .type_(type_located)
+        .initializer(initializer)
+        .build(),
+    )
+  }
+
+  fn parse_alias(&mut self) -> Result<TypeAlias<'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Type])?;
+    let name_token = self.expect(smallvec![TokenKind::Identifier])?;
+    self.expect(smallvec![TokenKind::Assign])?;
+    let (type_, type_location) = self.parse_type()?;
+    self.expect(smallvec![TokenKind::Semicolon])?;
+
+    let name_located = Located::builder()
+      .value(self.intern_identifier(&name_token))
+      .location(name_token.location().clone())
+      .build();
+    let type_located = Located::builder()
+      .value(type_)
+      .location(type_location)
+      .build();
+
+    Ok(
+      TypeAlias::builder()
+        .name(name_located)
+        .type_(type_located)
+        .build(),
+    )
+  }
+
+  fn parse_import(&mut self) -> Result<Import<'src>, ParseErrorMOO<'src>> {
+    self.expect(smallvec![TokenKind::Import])?;
+    let target_token = self.expect(smallvec![TokenKind::Identifier, TokenKind::String])?;
+    self.expect(smallvec![TokenKind::Semicolon])?;
+
+    let (kind, target) = match target_token.data() {
+      Some(TokenData::String(path)) => (ImportKind::File, self.strintern.intern(path)),
+      _ => (ImportKind::Module, self.intern_identifier(&target_token)),
+    };
+    let target_located = Located::builder()
+      .value(target)
+      .location(target_token.location().clone())
+      .build();
+
+    Ok(
+      Import::builder()
+        .kind(kind)
+        .target(target_located)
+        .build(),
+    )
+  }
+
+  /// Takes the doc comments before the next token. Those documenting the file
+  /// go to the `Ast`; the others are returned for the item that follows.
+  fn take_docs(&mut self) -> Vec<Located<'src, &'src str>> {
+    let mut docs = Vec::new();
+    for token in self.lexer.take_docs() {
+      let doc = Located::builder()
+        .value(token.doc_text().unwrap())
+        .location(token.location().clone())
+        .build();
+      match token.kind() {
+        TokenKind::InnerDocComment => self.ast.docs_mut().push(doc),
+        _ => docs.push(doc),
+      }
+    }
+    docs
+  }
+
+  /// Parses an item with `parse` and adds it to the `Ast` with `add`. An item
+  /// that fails to parse is reported and skipped up to the next item.
+  fn parse_item<T>(
+    &mut self,
+    parse: impl FnOnce(&mut Self) -> Result<T, ParseErrorMOO<'src>>,
+    add: impl FnOnce(&mut Ast<'ast, 'src>, T),
+  ) -> Result<(), ()> {
+    match parse(self) {
+      Ok(item) => {
+        add(self.ast, item);
+        Ok(())
+      }
+      Err(error) => {
+        self.handle_parse_error(error);
+        self.sync(item_start());
+        Err(())
+      }
+    }
+  }
+
+  pub fn parse(&mut self) -> Result<(), ()> {
+    self.lexer.reset();
+
+    let mut status = ParseStatus::Success;
+    let expected = smallvec![
+      TokenKind::Func,
+      TokenKind::Extern,
+      TokenKind::Struct,
+      TokenKind::Enum,
+      TokenKind::Const,
+      TokenKind::Var,
+      TokenKind::Type,
+      TokenKind::Import,
+      TokenKind::Eof,
+    ];
+
+    loop {
+      let kind_tag = match self.peek(expected.clone()) {
+        Some(kind) => kind,
+        None => {
+          if let Err(error) = self.expect(expected.clone()) {
+            status = ParseStatus::Error;
+            self.handle_parse_error(error.into());
+            self.sync(item_start());
+            continue;
+          }
+          unreachable!("Expected error but got a valid token")
+        }
+      };
+
+      let docs = self.take_docs();
+      if kind_tag == TokenKind::Eof {
+        if let (Some(first), Some(last)) = (docs.first(), docs.last()) {
+          let run = first.location().merge(last.location()).unwrap();
+          self.sink.report(DocWarning::new(run).report()).unwrap();
+        }
+        break;
+      }
+
+      let parsed = switch!(kind_tag => {
+        TokenKind::Func => self.parse_item(
+          |parser| parser.parse_function(None),
+          |ast, mut func| {
+            func.set_docs(docs);
+            ast.funcs_mut().push(func);
+          },
+        ),
+        TokenKind::Extern => self.parse_item(Self::parse_extern, |ast, mut func| {
+          func.set_docs(docs);
+          ast.funcs_mut().push(func);
+        }),
+        TokenKind::Struct => self.parse_item(Self::parse_struct, |ast, mut structure| {
+          structure.set_docs(docs);
+          ast.structs_mut().push(structure);
+        }),
+        TokenKind::Enum => self.parse_item(Self::parse_enum, |ast, mut enumeration| {
+          enumeration.set_docs(docs);
+          ast.enums_mut().push(enumeration);
+        }),
+        TokenKind::Const | TokenKind::Var => self.parse_item(Self::parse_global, |ast, mut global| {
+          global.set_docs(docs);
+          ast.globals_mut().push(global);
+        }),
+        TokenKind::Type => self.parse_item(Self::parse_alias, |ast, mut alias| {
+          alias.set_docs(docs);
+          ast.aliases_mut().push(alias);
+        }),
+        TokenKind::Import => self.parse_item(Self::parse_import, |ast, mut import| {
+          import.set_docs(docs);
+          ast.imports_mut().push(import);
+        }),
+      });
+      if parsed.is_err() {
+        status = ParseStatus::Error;
+      }
+    }
+
+    for run in self.lexer.take_stray_docs() {
+      self.sink.report(DocWarning::new(run).report()).unwrap();
+    }
+    self.lint_identifiers();
+
+    match status {
+      ParseStatus::Success => Ok(()),
+      ParseStatus::Error => Err(()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use ariadne::Report;
+  use heaped::arena::DroplessArena;
+  use sable_arena::TypedArena;
+  use sable_ast::{
+    ast::Arenas,
+    expression::BlockExpression,
+    objects::function::Function,
+  };
+  use sable_common::file::{
+    Span,
+    source::Source,
+  };
+
+  use super::*;
+
+  /// Keeps reports instead of printing them. Reports only expose their
+  /// message through `Debug`, so that is what is kept.
+  #[derive(Default)]
+  struct Reports(Vec<String>);
+
+  impl<'src> Sink<'src> for Reports {
+    type Error = ();
+
+    fn report(&mut self, report: Report<'_, Span<'src>>) -> Result<(), Self::Error> {
+      self.0.push(format!("{:?}", report));
+      Ok(())
+    }
+  }
+
+  struct Parsed<'p, 'ast, 'src> {
+    ast: &'p Ast<'ast, 'src>,
+    intern: &'p StrInterner<'src>,
+    reports: Vec<String>,
+    ok: bool,
+  }
+
+  impl<'p, 'ast, 'src> Parsed<'p, 'ast, 'src> {
+    fn name(&self, entry: Entry) -> &'src str {
+      self.intern.resolve(entry).unwrap()
+    }
+
+    fn reported(&self, message: &str) -> bool {
+      self.reports.iter().any(|report| report.contains(message))
+    }
+
+    fn func(&self, name: &str) -> &'p Function<'ast, 'src> {
+      self
+        .ast
+        .funcs()
+        .iter()
+        .find(|func| self.name(*func.name().value()) == name)
+        .unwrap()
+    }
+
+    /// The fields of struct `name` as `field: Type`.
+    fn fields(&self, name: &str) -> Vec<String> {
+      let item = self
+        .ast
+        .structs()
+        .iter()
+        .find(|item| self.name(*item.name().value()) == name)
+        .unwrap();
+      item
+        .fields()
+        .iter()
+        .map(|field| {
+          format!(
+            "{}: {}",
+            self.name(*field.name().value()),
+            self.render_type(field.type_().value())
+          )
+        })
+        .collect()
+    }
+
+    /// The variants of enum `name` as `Variant(Payload, ..)`.
+    fn variants(&self, name: &str) -> Vec<String> {
+      let item = self
+        .ast
+        .enums()
+        .iter()
+        .find(|item| self.name(*item.name().value()) == name)
+        .unwrap();
+      item
+        .variants()
+        .iter()
+        .map(|variant| {
+          let name = self.name(*variant.name().value());
+          if variant.payload().is_empty() {
+            return name.to_string();
+          }
+          let payload: Vec<_> = variant
+            .payload()
+            .iter()
+            .map(|ty| self.render_type(ty.value()))
+            .collect();
+          format!("{}({})", name, payload.join(", "))
+        })
+        .collect()
+    }
+
+    /// Every global as `kind name: Type = value`.
+    fn globals(&self) -> Vec<String> {
+      self
+        .ast
+        .globals()
+        .iter()
+        .map(|global| {
+          format!(
+            "{:?} {}: {} = {}",
+            global.kind(),
+            self.name(*global.name().value()),
+            self.render_type(global.type_().value()),
+            self.render(global.initializer())
+          )
+        })
+        .collect()
+    }
+
+    fn body(&self, name: &str) -> String {
+      self.render_block(self.func(name).block().as_ref().unwrap())
+    }
+
+    fn render_path(&self, path: &Path<'src>) -> String {
+      let mut rendered = path
+        .segments()
+        .iter()
+        .map(|segment| self.name(*segment.value()))
+        .collect::<Vec<_>>()
+        .join("::");
+      if !path.generics().is_empty() {
+        let args: Vec<_> = path.generics().iter().map(|arg| self.render_type(arg)).collect();
+        rendered = format!("{}<{}>", rendered, args.join(", "));
+      }
+      rendered
+    }
+
+    fn render_type(&self, ty: &Type<'src>) -> String {
+      match ty {
+        Type::Infer => "_".to_string(),
+        Type::Path(path) => self.render_path(path),
+        Type::Pointer(inner) => format!("{}*", self.render_type(inner)),
+        Type::Array(inner, length) => format!("[{}; {}]", self.render_type(inner), length),
+        Type::Slice(inner) => format!("[{}]", self.render_type(inner)),
+        Type::Function(params, return_type) => {
+          let params: Vec<_> = params.iter().map(|param| self.render_type(param)).collect();
+          format!("func({}): {}", params.join(", "), self.render_type(return_type))
+        }
+      }
+    }
+
+    fn render_literal(&self, literal: &LiteralExpression) -> String {
+      match literal {
+        LiteralExpression::Integer(integer) => format!(
+          "{}{}{}",
+          if *integer.negative() { "-" } else { "" },
+          integer.value(),
+          integer.suffix().map_or("", |suffix| suffix.name())
+        ),
+        LiteralExpression::Float(float) => format!(
+          "{:?}{}",
+          float.value(),
+          float.suffix().map_or("", |suffix| suffix.name())
+        ),
+        LiteralExpression::String(string) => format!("{:?}", self.name(*string.value())),
+        LiteralExpression::Char(char) => format!("{:?}", char.value()),
+        LiteralExpression::Bool(bool) => bool.value().to_string(),
+      }
+    }
+
+    fn render_pattern(&self, pattern: &Pattern<'_, 'src>) -> String {
+      match pattern.kind() {
+        PatternKind::Wildcard => "_".to_string(),
+        PatternKind::Binding(name) => self.name(*name).to_string(),
+        PatternKind::Literal(literal) => self.render_literal(literal),
+        PatternKind::Variant(variant) if variant.fields().is_empty() => {
+          self.render_path(variant.path())
+        }
+        PatternKind::Variant(variant) => {
+          let fields: Vec<_> = variant
+            .fields()
+            .iter()
+            .map(|field| self.render_pattern(field))
+            .collect();
+          format!("{}({})", self.render_path(variant.path()), fields.join(", "))
+        }
+      }
+    }
+
+    /// Writes statements followed by `;` and the tail without one.
+    fn render_block(&self, block: &BlockExpression<'_, 'src>) -> String {
+      let mut parts: Vec<_> = block
+        .body()
+        .iter()
+        .map(|statement| format!("{};", self.render_statement(statement)))
+        .collect();
+      if let Some(tail) = block.tail() {
+        parts.push(self.render(tail));
+      }
+      format!("{{{}}}", parts.join(" "))
+    }
+
+    fn render_statement(&self, statement: &Statement<'_, 'src>) -> String {
+      match statement.kind() {
+        StatementKind::Expression(expr) => self.render(expr),
+        StatementKind::Variable(variable) => match variable.type_().value() {
+          Type::Infer => format!(
+            "(var {} {})",
+            self.name(*variable.name().value()),
+            self.render(variable.initializer())
+          ),
+          ty => format!(
+            "(var {}: {} {})",
+            self.name(*variable.name().value()),
+            self.render_type(ty),
+            self.render(variable.initializer())
+          ),
+        },
+        StatementKind::Return(return_) => match return_.value() {
+          Some(value) => format!("(return {})", self.render(value)),
+          None => "(return)".to_string(),
+        },
+        StatementKind::Break(break_) => match break_.value() {
+          Some(value) => format!("(break {})", self.render(value)),
+          None => "(break)".to_string(),
+        },
+        StatementKind::Continue => "(continue)".to_string(),
+      }
+    }
+
+    /// Writes an expression as an S-expression, so that the grouping the
+    /// parser chose is spelled out.
+    fn render(&self, expr: &Expression<'_, 'src>) -> String {
+      match expr.kind() {
+        ExpressionKind::Block(block) => self.render_block(block),
+        ExpressionKind::Literal(literal) => self.render_literal(literal),
+        ExpressionKind::Assign(assign) => format!(
+          "({}= {} {})",
+          assign.operator().map_or(String::new(), |operator| format!("{:?}", operator)),
+          self.render(assign.target()),
+          self.render(assign.value())
+        ),
+        ExpressionKind::Binary(binary) => format!(
+          "({:?} {} {})",
+          binary.operator(),
+          self.render(binary.lhs()),
+          self.render(binary.rhs())
+        ),
+        ExpressionKind::Unary(unary) => {
+          format!("({:?} {})", unary.operator(), self.render(unary.operand()))
+        }
+        ExpressionKind::Call(call) => {
+          let mut parts = vec!["call".to_string(), self.render(call.callee())];
+          parts.extend(call.args().iter().map(|arg| self.render(arg)));
+          format!("({})", parts.join(" "))
+        }
+        ExpressionKind::If(if_) => match if_.else_branch() {
+          Some(else_branch) => format!(
+            "(if {} {} {})",
+            self.render(if_.condition()),
+            self.render_block(if_.then_block()),
+            self.render(else_branch)
+          ),
+          None => format!(
+            "(if {} {})",
+            self.render(if_.condition()),
+            self.render_block(if_.then_block())
+          ),
+        },
+        ExpressionKind::While(while_) => format!(
+          "(while {} {})",
+          self.render(while_.condition()),
+          self.render_block(while_.body())
+        ),
+        ExpressionKind::Loop(loop_) => format!("(loop {})", self.render_block(loop_.body())),
+        ExpressionKind::Match(match_) => {
+          let mut parts = vec!["match".to_string(), self.render(match_.scrutinee())];
+          parts.extend(match_.arms().iter().map(|arm| {
+            format!(
+              "({} => {})",
+              self.render_pattern(arm.pattern()),
+              self.render(arm.body())
+            )
+          }));
+          format!("({})", parts.join(" "))
+        }
+        ExpressionKind::StructLiteral(struct_literal) => {
+          let mut parts = vec![self.render_path(struct_literal.path())];
+          parts.extend(struct_literal.fields().iter().map(|field| {
+            format!(
+              "({} {})",
+              self.name(*field.name().value()),
+              self.render(field.value())
+            )
+          }));
+          format!("(struct {})", parts.join(" "))
+        }
+        ExpressionKind::Field(field) => format!(
+          "(. {} {})",
+          self.render(field.object()),
+          self.name(*field.field().value())
+        ),
+        ExpressionKind::Array(ArrayExpression::List(list)) => {
+          let elements: Vec<_> = list.elements().iter().map(|element| self.render(element)).collect();
+          format!("[{}]", elements.join(", "))
+        }
+        ExpressionKind::Array(ArrayExpression::Repeat(repeat)) => format!(
+          "[{}; {}]",
+          self.render(repeat.value()),
+          self.render(repeat.count())
+        ),
+        ExpressionKind::Index(index) => format!(
+          "(index {} {})",
+          self.render(index.object()),
+          self.render(index.index())
+        ),
+        ExpressionKind::Cast(cast) => format!(
+          "(as {} {})",
+          self.render(cast.value()),
+          self.render_type(cast.type_().value())
+        ),
+        ExpressionKind::Identifier(identifier) => self.render_path(identifier.path()),
+      }
+    }
+  }
+
+  fn parse<R>(content: &str, check: impl FnOnce(&Parsed<'_, '_, '_>) -> R) -> R {
+    let sources = TypedArena::new();
+    let source = Arc::new_in(Source::new(content, "test.sable", &sources), &sources);
+    let strings = DroplessArena::new(4096);
+    let intern = StrInterner::new(&strings);
+    let arenas = Arenas::default();
+    let mut ast = Ast::new(source.filename(), &arenas);
+
+    let mut reports = Reports::default();
+    let ok = Parser::new(Lexer::new(source.clone()), &mut ast, &mut reports, &intern)
+      .parse()
+      .is_ok();
+
+    check(&Parsed {
+      ast: &ast,
+      intern: &intern,
+      reports: reports.0,
+      ok,
+    })
+  }
+
+  /// Parses `body` as the body of a function and renders it.
+  fn body(body: &str) -> String {
+    parse(&format!("func f(): i32 {{ {} }}", body), |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      parsed.body("f")
+    })
+  }
+
+  /// Parses `expr` as the value of a function and renders it.
+  fn expr(expr: &str) -> String {
+    let rendered = body(expr);
+    rendered[1..rendered.len() - 1].to_string()
+  }
+
+  #[test]
+  fn test_binary_precedence() {
+    assert_eq!(expr("a + b * c"), "(Add a (Multiply b c))");
+    assert_eq!(expr("a * b + c * d"), "(Add (Multiply a b) (Multiply c d))");
+    assert_eq!(
+      expr("a || b && c | d ^ e & f == g < h << i + j * k"),
+      "(LogicalOr a (LogicalAnd b (BitOr c (BitXor d (BitAnd e (Equal f (Less g \
+       (ShiftLeft h (Add i (Multiply j k))))))))))"
+    );
+    assert_eq!(
+      expr("a * b + c << d > e != f & g ^ h | i && j || k"),
+      "(LogicalOr (LogicalAnd (BitOr (BitXor (BitAnd (NotEqual (Greater (ShiftLeft \
+       (Add (Multiply a b) c) d) e) f) g) h) i) j) k)"
+    );
+    assert_eq!(expr("(a + b) * c"), "(Multiply (Add a b) c)");
+  }
+
+  #[test]
+  fn test_binary_associativity() {
+    assert_eq!(expr("a - b - c"), "(Subtract (Subtract a b) c)");
+    assert_eq!(expr("a / b % c * d"), "(Multiply (Remainder (Divide a b) c) d)");
+    assert_eq!(expr("a == b != c"), "(NotEqual (Equal a b) c)");
+    assert_eq!(expr("a << b >> c"), "(ShiftRight (ShiftLeft a b) c)");
+    assert_eq!(expr("a - (b - c)"), "(Subtract a (Subtract b c))");
+  }
+
+  #[test]
+  fn test_unary() {
+    assert_eq!(expr("-a * b"), "(Multiply (Negate a) b)");
+    assert_eq!(expr("!a && b"), "(LogicalAnd (Not a) b)");
+    assert_eq!(expr("--a"), "(Negate (Negate a))");
+    assert_eq!(expr("*f(x)"), "(Deref (call f x))");
+    assert_eq!(expr("*p.x"), "(Deref (. p x))");
+    assert_eq!(expr("&a[0]"), "(AddressOf (index a 0))");
+    assert_eq!(expr("&&a"), "(AddressOf (AddressOf a))");
+    assert_eq!(expr("a - -b"), "(Subtract a (Negate b))");
+    assert_eq!(expr("a * *b"), "(Multiply a (Deref b))");
+  }
+
+  #[test]
+  fn test_call() {
+    assert_eq!(expr("f()"), "(call f)");
+    assert_eq!(expr("f(a, b + c)"), "(call f a (Add b c))");
+    assert_eq!(expr("f(a,)"), "(call f a)");
+    assert_eq!(expr("f(a)(b)"), "(call (call f a) b)");
+    assert_eq!(expr("f(g(a), h())"), "(call f (call g a) (call h))");
+    assert_eq!(expr("s.f(a)[0]"), "(index (call (. s f) a) 0)");
+    assert_eq!(expr("-f(a) * 2"), "(Multiply (Negate (call f a)) 2)");
+  }
+
+  #[test]
+  fn test_call_unclosed() {
+    parse("func f(): i32 { g(a, b }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_return_and_tail() {
+    assert_eq!(body("return 1;"), "{(return 1);}");
+    assert_eq!(body("return;"), "{(return);}");
+    assert_eq!(body("var x = 1; x"), "{(var x 1); x}");
+    assert_eq!(body("var x: i32 = 1; x + 1;"), "{(var x: i32 1); (Add x 1);}");
+    assert_eq!(body(""), "{}");
+  }
+
+  #[test]
+  fn test_return_missing_semicolon() {
+    parse("func f(): i32 { return 1 }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_if_else() {
+    assert_eq!(expr("if a { 1 } else { 2 }"), "(if a {1} {2})");
+    assert_eq!(
+      expr("if a { 1 } else if b { 2 } else { 3 }"),
+      "(if a {1} (if b {2} {3}))"
+    );
+    assert_eq!(expr("if a && b { f(); }"), "(if (LogicalAnd a b) {(call f);})");
+    assert_eq!(
+      body("var x = if a { 1 } else { 2 }; x"),
+      "{(var x (if a {1} {2})); x}"
+    );
+    assert_eq!(body("if a { f(); } g()"), "{(if a {(call f);}); (call g)}");
+  }
+
+  #[test]
+  fn test_if_missing_block() {
+    parse("func f(): i32 { if a 1 }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_loops() {
+    assert_eq!(expr("while a < b { a += 1; }"), "(while (Less a b) {(Add= a 1);})");
+    assert_eq!(expr("loop { break; }"), "(loop {(break);})");
+    assert_eq!(body("var x = loop { break 1; }; x"), "{(var x (loop {(break 1);})); x}");
+    assert_eq!(
+      body("while true { if a { continue; } } 0"),
+      "{(while true {(if a {(continue);})}); 0}"
+    );
+  }
+
+  #[test]
+  fn test_struct_declaration() {
+    parse("struct Point { x: i32, y: i32* } struct Empty {}", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert_eq!(parsed.fields("Point"), ["x: i32", "y: i32*"]);
+      assert!(parsed.fields("Empty").is_empty());
+    });
+  }
+
+  #[test]
+  fn test_struct_literal() {
+    assert_eq!(expr("Point { x: 1, y: a + b }"), "(struct Point (x 1) (y (Add a b)))");
+    assert_eq!(expr("Point {}"), "(struct Point)");
+    assert_eq!(
+      expr("Line { from: Point { x: 1 }, to: p }"),
+      "(struct Line (from (struct Point (x 1))) (to p))"
+    );
+    assert_eq!(expr("Point { x: 1 }.x"), "(. (struct Point (x 1)) x)");
+  }
+
+  #[test]
+  fn test_struct_literal_in_condition() {
+    // `struct_literals` is off in conditions, so the brace opens the body.
+    assert_eq!(expr("if a { b } else { c }"), "(if a {b} {c})");
+    assert_eq!(expr("while p.x { }"), "(while (. p x) {})");
+    // Parentheses and blocks turn it back on.
+    assert_eq!(
+      expr("if (P { x: 1 }).x { b }"),
+      "(if (. (struct P (x 1)) x) {b})"
+    );
+    assert_eq!(
+      expr("if a { P { x: 1 } } else { b }"),
+      "(if a {(struct P (x 1))} {b})"
+    );
+  }
+
+  #[test]
+  fn test_field_access() {
+    assert_eq!(expr("a.b.c"), "(. (. a b) c)");
+    assert_eq!(expr("(*p).x"), "(. (Deref p) x)");
+    assert_eq!(expr("p.x + 1"), "(Add (. p x) 1)");
+    assert_eq!(body("p.x = 1;"), "{(= (. p x) 1);}");
+  }
+
+  #[test]
+  fn test_paths() {
+    assert_eq!(expr("a::b::c"), "a::b::c");
+    assert_eq!(expr("math::max(a, b)"), "(call math::max a b)");
+    assert_eq!(expr("geo::Point { x: 1 }"), "(struct geo::Point (x 1))");
+    assert_eq!(body("var p: geo::Point* = q; p"), "{(var p: geo::Point* q); p}");
+    parse("func f(p: a::b::T): a::U { p }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      let func = parsed.func("f");
+      assert_eq!(parsed.render_type(func.params()[0].type_().value()), "a::b::T");
+      assert_eq!(parsed.render_type(func.return_type().value()), "a::U");
+    });
+  }
+
+  #[test]
+  fn test_path_missing_segment() {
+    parse("func f(): i32 { a:: }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_globals() {
+    parse("const MAX: u8 = 1 as u8; var count: i32 = -MAX * 2;", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert_eq!(
+        parsed.globals(),
+        [
+          "Const MAX: u8 = (as 1 u8)",
+          "Var count: i32 = (Multiply (Negate MAX) 2)"
+        ]
+      );
+    });
+  }
+
+  #[test]
+  fn test_global_errors() {
+    parse("const MAX: u8;", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_item_recovery() {
+    // Recovery skips the rest of the broken struct instead of taking its
+    // `var` for a global.
+    parse(
+      "struct S { x: i32 var y: i32 } func g(): i32 { 0 } const C: i32 = 1;",
+      |parsed| {
+        assert!(!parsed.ok);
+        assert_eq!(parsed.reports.len(), 1, "{:#?}", parsed.reports);
+        assert_eq!(parsed.body("g"), "{0}");
+        assert_eq!(parsed.globals(), ["Const C: i32 = 1"]);
+      },
+    );
+
+    // A broken function is skipped past its local `var` statements too.
+    parse(
+      "func f(): i32 { var x: i32 = ; var y: i32 = 2; y } extern \"C\" func exit(code: i32): i32;",
+      |parsed| {
+        assert!(!parsed.ok);
+        assert_eq!(parsed.reports.len(), 1, "{:#?}", parsed.reports);
+        assert!(parsed.globals().is_empty());
+        assert!(parsed.func("exit").is_import());
+      },
+    );
+  }
+
+  #[test]
+  fn test_extern() {
+    parse(
+      "extern \"C\" func printf(format: u8*, ...): i32; extern \"C\" func exit(code: i32): i32;",
+      |parsed| {
+        assert!(parsed.ok, "{:#?}", parsed.reports);
+        let printf = parsed.func("printf");
+        assert!(printf.is_import() && *printf.variadic());
+        assert_eq!(parsed.name(*printf.abi().as_ref().unwrap().value()), "C");
+        assert_eq!(printf.params().len(), 1);
+        assert!(printf.block().is_none());
+        assert!(!parsed.func("exit").variadic());
+      },
+    );
+  }
+
+  #[test]
+  fn test_extern_errors() {
+    let cases = [
+      ("extern \"Rust\" func f(): i32;", "Unsupported ABI"),
+      ("extern \"C\" func f(): i32 { 0 }", "External function cannot have a body"),
+      ("func f(a: i32, ...): i32 { 0 }", "Only external functions can be variadic"),
+    ];
+    for (content, message) in cases {
+      parse(content, |parsed| {
+        assert!(!parsed.ok);
+        assert!(parsed.reported(message), "{:#?}", parsed.reports);
+      });
+    }
+  }
+
+  #[test]
+  fn test_enum_declaration() {
+    parse("enum Shape { Circle(f64), Rect(f64, f64), Empty }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert_eq!(parsed.variants("Shape"), ["Circle(f64)", "Rect(f64, f64)", "Empty"]);
+    });
+  }
+
+  #[test]
+  fn test_match() {
+    assert_eq!(
+      expr("match s { Shape::Circle(r) => r, Shape::Rect(w, _) => { w } Empty => 0 }"),
+      "(match s (Shape::Circle(r) => r) (Shape::Rect(w, _) => {w}) (Empty => 0))"
+    );
+    assert_eq!(
+      expr("match n { 0 => a, -1 => b, true => c, x => x, }"),
+      "(match n (0 => a) (-1 => b) (true => c) (x => x))"
+    );
+    assert_eq!(expr("match p.x { _ => P { x: 1 } }"), "(match (. p x) (_ => (struct P (x 1))))");
+  }
+
+  #[test]
+  fn test_match_missing_arrow() {
+    parse("func f(): i32 { match s { _ 0 } }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_nested_type_args() {
+    assert_eq!(body("var p: Pair<Pair<i32>> = q; p"), "{(var p: Pair<Pair<i32>> q); p}");
+    assert_eq!(expr("wrap<Pair<i32>>(p)"), "(call wrap<Pair<i32>> p)");
+    assert_eq!(expr("Pair<Pair<i32>> { a: p }"), "(struct Pair<Pair<i32>> (a p))");
+    assert_eq!(expr("a >> b"), "(ShiftRight a b)");
+    assert_eq!(expr("a < b >> c"), "(Less a (ShiftRight b c))");
+  }
+
+  #[test]
+  fn test_arrays() {
+    assert_eq!(
+      body("var a: [i32; 3] = [1, 2, 3]; a[0]"),
+      "{(var a: [i32; 3] [1, 2, 3]); (index a 0)}"
+    );
+    assert_eq!(
+      body("var b: [[u8; 2]; 4] = [[0; 2]; 4]; b"),
+      "{(var b: [[u8; 2]; 4] [[0; 2]; 4]); b}"
+    );
+    assert_eq!(expr("m[i][j]"), "(index (index m i) j)");
+    parse("func f(s: [u8]): [u8]* { &s }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      let func = parsed.func("f");
+      assert_eq!(parsed.render_type(func.params()[0].type_().value()), "[u8]");
+      assert_eq!(parsed.render_type(func.return_type().value()), "[u8]*");
+    });
+  }
+
+  #[test]
+  fn test_array_length_too_large() {
+    parse("var a: [i32; 18446744073709551616] = q;", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Integer literal `18446744073709551616` does not fit in `u64`"));
+    });
+    parse("var a: [i32; 18446744073709551615] = q;", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+    });
+  }
+
+  #[test]
+  fn test_cast() {
+    assert_eq!(expr("x as i64"), "(as x i64)");
+    assert_eq!(expr("a * b as i64"), "(Multiply a (as b i64))");
+    assert_eq!(expr("-x as u8"), "(as (Negate x) u8)");
+    assert_eq!(expr("p as i8* as usize"), "(as (as p i8*) usize)");
+    assert_eq!(expr("f(x) as math::Fixed"), "(as (call f x) math::Fixed)");
+  }
+
+  #[test]
+  fn test_cast_missing_type() {
+    parse("func f(): i32 { x as }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_assign_targets() {
+    assert_eq!(
+      body("*p = 1; a[i] = 2; s.x.y = 3;"),
+      "{(= (Deref p) 1); (= (index a i) 2); (= (. (. s x) y) 3);}"
+    );
+    assert_eq!(body("a = b = 1;"), "{(= a (= b 1));}");
+    assert_eq!(body("*p[0] = 1;"), "{(= (Deref (index p 0)) 1);}");
+  }
+
+  #[test]
+  fn test_compound_assign() {
+    assert_eq!(
+      body("a -= 1; a *= 2; a /= 3; a %= 4; a &= 5;"),
+      "{(Subtract= a 1); (Multiply= a 2); (Divide= a 3); (Remainder= a 4); (BitAnd= a 5);}"
+    );
+    assert_eq!(
+      body("a |= 6; a ^= 7; a <<= 8; a >>= 9;"),
+      "{(BitOr= a 6); (BitXor= a 7); (ShiftLeft= a 8); (ShiftRight= a 9);}"
+    );
+    assert_eq!(body("s.n += 1 + 2;"), "{(Add= (. s n) (Add 1 2));}");
+  }
+
+  #[test]
+  fn test_assign_to_rvalue() {
+    for statement in ["1 = 2;", "f() = 1;", "a + b += 1;", "-a = 1;", "x as i64 = 1;"] {
+      parse(&format!("func f(): i32 {{ {} 0 }}", statement), |parsed| {
+        assert!(!parsed.ok, "{}", statement);
+        assert!(parsed.reported("Cannot assign to this expression"), "{}", statement);
+      });
+    }
+  }
+
+  #[test]
+  fn test_bool_literals() {
+    assert_eq!(expr("true"), "true");
+    assert_eq!(expr("true && !false"), "(LogicalAnd true (Not false))");
+    assert_eq!(expr("a == b || false"), "(LogicalOr (Equal a b) false)");
+  }
+
+  #[test]
+  fn test_type_alias() {
+    parse("type Argv = i8**; type Grid = [[u8; 3]; 3];", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      let aliases: Vec<_> = parsed
+        .ast
+        .aliases()
+        .iter()
+        .map(|alias| {
+          format!(
+            "{} = {}",
+            parsed.name(*alias.name().value()),
+            parsed.render_type(alias.type_().value())
+          )
+        })
+        .collect();
+      assert_eq!(aliases, ["Argv = i8**", "Grid = [[u8; 3]; 3]"]);
+    });
+
+    parse("type Argv i8**;", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_function_types() {
+    parse("func f(cb: func(i32, u8*): i32): func(): bool { cb }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      let func = parsed.func("f");
+      assert_eq!(
+        parsed.render_type(func.params()[0].type_().value()),
+        "func(i32, u8*): i32"
+      );
+      assert_eq!(parsed.render_type(func.return_type().value()), "func(): bool");
+    });
+    parse("struct S { cb: func(func(i32): i32): i32* }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert_eq!(parsed.fields("S"), ["cb: func(func(i32): i32): i32*"]);
+    });
+    assert_eq!(body("var g: func(i32): i32 = f; g(1)"), "{(var g: func(i32): i32 f); (call g 1)}");
+    assert_eq!(expr("s.cb(1)"), "(call (. s cb) 1)");
+  }
+
+  #[test]
+  fn test_function_type_errors() {
+    parse("func f(cb: func(i32): i32 { cb }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+    parse("func f(cb: func(i32)) { cb }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_imports() {
+    parse("import math; import \"lib/other.sable\"; func f(): i32 { math::add(1, 2) }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      let imports: Vec<_> = parsed
+        .ast
+        .imports()
+        .iter()
+        .map(|import| format!("{:?} {}", import.kind(), parsed.name(*import.target().value())))
+        .collect();
+      assert_eq!(imports, ["Module math", "File lib/other.sable"]);
+    });
+
+    parse("import math::add;", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
+  }
+
+  #[test]
+  fn test_item_docs() {
+    let source = concat!(
+      "//! Shapes.\n",
+      "/// The math module.\nimport math;\n",
+      "/// A point.\nstruct Point { x: i32 }\n",
+      "/// A color.\nenum Color { Red }\n",
+      "/// The origin.\nconst ZERO: i32 = 0;\n",
+      "/// A distance.\ntype Meters = f64;\n",
+      "/// Adds.\nfunc add(a: i32, b: i32): i32 { a + b }\n",
+    );
+    parse(source, |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      fn text<'src>(docs: &[Located<'src, &'src str>]) -> Vec<&'src str> {
+        docs.iter().map(|doc| *doc.value()).collect()
+      }
+      assert_eq!(text(parsed.ast.docs()), ["Shapes."]);
+      assert_eq!(text(parsed.ast.imports()[0].docs()), ["The math module."]);
+      assert_eq!(text(parsed.ast.structs()[0].docs()), ["A point."]);
+      assert_eq!(text(parsed.ast.enums()[0].docs()), ["A color."]);
+      assert_eq!(text(parsed.ast.globals()[0].docs()), ["The origin."]);
+      assert_eq!(text(parsed.ast.aliases()[0].docs()), ["A distance."]);
+      assert_eq!(text(parsed.ast.funcs()[0].docs()), ["Adds."]);
+    });
+  }
+
+  #[test]
+  fn test_stray_docs() {
+    let source = concat!(
+      "func f(): i32 {\n  /// The answer.\n  /// Still the answer.\n  42\n}\n",
+      "/// Nothing.\n",
+    );
+    parse(source, |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      let message = "Doc comment does not document anything";
+      let strays = parsed.reports.iter().filter(|report| report.contains(message));
+      assert_eq!(strays.count(), 2, "{:#?}", parsed.reports);
+    });
+  }
+
+  #[test]
+  fn test_identifier_normalization() {
+    parse("func cafe\u{301}(): i32 { 1 } func caf\u{e9}(): i32 { 1 }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert!(parsed.reports.is_empty(), "{:#?}", parsed.reports);
+      let funcs = parsed.ast.funcs();
+      assert_eq!(funcs[0].name().value(), funcs[1].name().value());
+      assert_eq!(parsed.name(*funcs[0].name().value()), "caf\u{e9}");
+    });
+  }
+
+  #[test]
+  fn test_identifier_lints() {
+    // `p\u{430}ypal` spells its `a` with the Cyrillic letter.
+    parse("func p\u{430}ypal(): i32 { 1 }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert!(parsed.reported("Identifier `p\u{430}ypal` mixes scripts"));
+    });
+
+    // `\u{430}\u{440}\u{435}` is Cyrillic throughout but reads as `ape`.
+    parse("func ape(): i32 { 1 } func \u{430}\u{440}\u{435}(): i32 { 1 }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert!(parsed.reported("Identifier `\u{430}\u{440}\u{435}` looks like `ape`"));
+      assert!(!parsed.reported("mixes scripts"));
+    });
+
+    parse("func rn(): i32 { 1 } func m(): i32 { 1 } func l(): i32 { I }", |parsed| {
+      assert!(parsed.ok, "{:#?}", parsed.reports);
+      assert!(parsed.reports.is_empty(), "{:#?}", parsed.reports);
+    });
+  }
+
+  #[test]
+  fn test_binary_missing_operand() {
+    parse("func f(): i32 { a + }", |parsed| {
+      assert!(!parsed.ok);
+      assert!(parsed.reported("Unexpected token"));
+    });
   }
 }
@@ -2,40 +2,143 @@ lexer grammar SableLexer;
 
 FUNC        : 'func';
 VAR         : 'var';
+RETURN      : 'return';
+IF          : 'if';
+ELSE        : 'else';
+WHILE       : 'while';
+LOOP        : 'loop';
+BREAK       : 'break';
+CONTINUE    : 'continue';
+STRUCT      : 'struct';
+CONST       : 'const';
+EXTERN      : 'extern';
+ENUM        : 'enum';
+MATCH       : 'match';
+AS          : 'as';
+TRUE        : 'true';
+FALSE       : 'false';
+TYPE        : 'type';
+IMPORT      : 'import';
+UNDERSCORE  : '_';
+COLONCOLON  : '::';
 COLON       : ':';
+ELLIPSIS    : '...';
+DOT         : '.';
 SEMI        : ';';
 COMMA       : ',';
+FATARROW    : '=>';
+EQ          : '==';
+NEQ         : '!=';
+BANG        : '!';
+LE          : '<=';
+GE          : '>=';
+SHL         : '<<';
+SHR         : '>>';
+LT          : '<';
+GT          : '>';
+ANDAND      : '&&';
+OROR        : '||';
+AMP         : '&';
+PIPE        : '|';
+CARET       : '^';
 ASSIGN      : '=';
+PLUSEQ      : '+=';
+MINUSEQ     : '-=';
+STAREQ      : '*=';
+SLASHEQ     : '/=';
+PERCENTEQ   : '%=';
+AMPEQ       : '&=';
+PIPEEQ      : '|=';
+CARETEQ     : '^=';
+SHLEQ       : '<<=';
+SHREQ       : '>>=';
 LPAREN      : '(';
 RPAREN      : ')';
 LBRACE      : '{';
 RBRACE      : '}';
+LBRACKET    : '[';
+RBRACKET    : ']';
 PLUS        : '+';
 MINUS       : '-';
 STAR        : '*';
 SLASH       : '/';
+PERCENT     : '%';
 
 IntegerLiteral
-  : [0-9]+
+  : ( [0-9] [0-9_]*
+    | '0x' [0-9a-fA-F_]+
+    | '0o' [0-7_]+
+    | '0b' [01_]+
+    ) IntegerSuffix?
   ;
 
 FloatLiteral
-  : [0-9]+ '.' [0-9]+
+  : ( [0-9] [0-9_]* '.' [0-9] [0-9_]* DecimalExponent?
+    | [0-9] [0-9_]* DecimalExponent
+    | '0x' [0-9a-fA-F_]+ ('.' HexDigit [0-9a-fA-F_]*)? BinaryExponent
+    ) FloatSuffix?
+  | [0-9] [0-9_]* FloatSuffix
   ;
 
+fragment DecimalExponent
+  : [eE] [+-]? [0-9_]+
+  ;
+
+fragment BinaryExponent
+  : [pP] [+-]? [0-9_]+
+  ;
+
+fragment FloatSuffix
+  : 'f32'
+  | 'f64'
+  ;
+
+fragment IntegerSuffix
+  : [iu] ('8' | '16' | '32' | '64' | 'size')
+  ;
+
+StringLiteral
+  : '"' (EscapeSequence | ~["\\\r\n])* '"'
+  ;
+
+CharLiteral
+  : '\'' (EscapeSequence | ~['\\\r\n]) '\''
+  ;
+
+fragment EscapeSequence
+  : '\\' [ntr0\\"']
+  | '\\x' [0-7] HexDigit
+  | '\\u{' HexDigit HexDigit? HexDigit? HexDigit? HexDigit? HexDigit? '}'
+  ;
+
+fragment HexDigit
+  : [0-9a-fA-F]
+  ;
+
+// Identifiers follow UAX #31 and are NFC-normalized before they are compared.
 Identifier
-  : [a-zA-Z_][a-zA-Z0-9_]*
+  : [_\p{XID_Start}] [\p{XID_Continue}]*
   ;
 
 WS
   : [ \t\r\n]+ -> skip
   ;
 
+// Doc comments are kept off the parser's channel and attached to the item
+// that follows (`///`) or to the file (`//!`).
+DOC_COMMENT
+  : '///' (~[/\r\n] ~[\r\n]*)? -> channel(HIDDEN)
+  ;
+
+INNER_DOC_COMMENT
+  : '//!' ~[\r\n]* -> channel(HIDDEN)
+  ;
+
 COMMENT
   : '//' ~[\r\n]* -> skip
   ;
 
 MULTILINE_COMMENT
-  : '/*' .*? '*/' -> skip
+  : '/*' (MULTILINE_COMMENT | .)*? '*/' -> skip
   ;
 
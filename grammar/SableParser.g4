@@ -3,11 +3,56 @@ parser grammar SableParser;
 options { tokenVocab=SableLexer; }
 
 program
-  : (functionDecl)* EOF
+  : (functionDecl | structDecl | enumDecl | globalDecl | typeAlias | importDecl)* EOF
+  ;
+
+// Aliases are transparent and may not refer to themselves, even through a
+// pointer.
+typeAlias
+  : TYPE identifier ASSIGN type SEMI
+  ;
+
+importDecl
+  : IMPORT (identifier | StringLiteral) SEMI
+  ;
+
+enumDecl
+  : ENUM identifier LBRACE (variant (COMMA variant)* COMMA?)? RBRACE
+  ;
+
+variant
+  : identifier (LPAREN (type (COMMA type)* COMMA?)? RPAREN)?
+  ;
+
+globalDecl
+  : (CONST | VAR) identifier COLON type ASSIGN expression SEMI
   ;
 
 functionDecl
-  : FUNC identifier LPAREN parameterList? RPAREN COLON type blockOrSemi
+  : FUNC identifier typeParams? LPAREN parameterList? RPAREN COLON type blockOrSemi
+  | externFunctionDecl
+  ;
+
+// Only "C" is accepted as the ABI string.
+externFunctionDecl
+  : EXTERN StringLiteral FUNC identifier LPAREN (parameterList (COMMA ELLIPSIS)? | ELLIPSIS)? RPAREN COLON type SEMI
+  ;
+
+structDecl
+  : STRUCT identifier typeParams? LBRACE fieldList? RBRACE
+  ;
+
+typeParams
+  : LT identifier (COMMA identifier)* GT
+  ;
+
+// A `>>` token closes two nested lists, as in `Pair<Pair<i32>>`.
+typeArgs
+  : LT type (COMMA type)* GT
+  ;
+
+fieldList
+  : parameter (COMMA parameter)* COMMA?
   ;
 
 parameterList
@@ -19,7 +64,24 @@ parameter
   ;
 
 type
-  : identifier pointerSuffix?
+  : (path typeArgs? | arrayType | sliceType | functionType) pointerSuffix?
+  ;
+
+// A trailing `*` belongs to the return type: `func(): i32*` returns a pointer.
+functionType
+  : FUNC LPAREN (type (COMMA type)*)? RPAREN COLON type
+  ;
+
+arrayType
+  : LBRACKET type SEMI IntegerLiteral RBRACKET
+  ;
+
+sliceType
+  : LBRACKET type RBRACKET
+  ;
+
+path
+  : identifier (COLONCOLON identifier)*
   ;
 
 pointerSuffix
@@ -32,11 +94,15 @@ blockOrSemi
   ;
 
 block
-  : LBRACE statement* RBRACE
+  : LBRACE statement* expression? RBRACE
   ;
 
 statement
   : variableDecl
+  | returnStmt
+  | breakStmt
+  | continueStmt
+  | blockLikeExpression SEMI?
   | expressionStmt
   ;
 
@@ -44,6 +110,18 @@ variableDecl
   : VAR identifier (COLON type)? ASSIGN expression SEMI
   ;
 
+returnStmt
+  : RETURN expression? SEMI
+  ;
+
+breakStmt
+  : BREAK expression? SEMI
+  ;
+
+continueStmt
+  : CONTINUE SEMI
+  ;
+
 expressionStmt
   : expression SEMI
   ;
@@ -53,8 +131,53 @@ expression
   ;
 
 assignment
-  : identifier ASSIGN expression
-  | additive
+  : assignTarget assignOperator expression
+  | logicalOr
+  ;
+
+assignOperator
+  : ASSIGN
+  | PLUSEQ | MINUSEQ | STAREQ | SLASHEQ | PERCENTEQ
+  | AMPEQ | PIPEEQ | CARETEQ | SHLEQ | SHREQ
+  ;
+
+assignTarget
+  : path
+  | STAR unary
+  | postfix DOT identifier
+  | postfix indexSuffix
+  ;
+
+logicalOr
+  : logicalAnd (OROR logicalAnd)*
+  ;
+
+logicalAnd
+  : bitOr (ANDAND bitOr)*
+  ;
+
+bitOr
+  : bitXor (PIPE bitXor)*
+  ;
+
+bitXor
+  : bitAnd (CARET bitAnd)*
+  ;
+
+bitAnd
+  : equality (AMP equality)*
+  ;
+
+equality
+  : comparison ((EQ | NEQ) comparison)*
+  ;
+
+comparison
+  : shift ((LT | LE | GT | GE) shift)*
+  ;
+
+shift
+  : additive ((SHL | SHR) additive)*
   ;
 
 additive
@@ -62,18 +185,124 @@ additive
   ;
 
 multiplicative
-  : primary ((STAR | SLASH) primary)*
+  : cast ((STAR | SLASH | PERCENT) cast)*
+  ;
+
+// After `as`, a `*` followed by an operand is a multiplication, and a `<`
+// only opens type arguments if they parse, so `x as i32 < y` compares.
+cast
+  : unary (AS type)*
+  ;
+
+unary
+  : (MINUS | BANG | AMP | ANDAND | STAR) unary
+  | postfix
+  ;
+
+postfix
+  : primary (callSuffix | fieldSuffix | indexSuffix)*
+  ;
+
+indexSuffix
+  : LBRACKET expression RBRACKET
+  ;
+
+fieldSuffix
+  : DOT identifier
+  ;
+
+callSuffix
+  : LPAREN argumentList? RPAREN
+  ;
+
+argumentList
+  : expression (COMMA expression)* COMMA?
   ;
 
 primary
   : literal
-  | identifier
+  | structLiteral
+  | instantiation
+  | path
+  | blockLikeExpression
+  | arrayLiteral
   | LPAREN expression RPAREN
   ;
 
+// The repeat count must be a compile-time constant.
+arrayLiteral
+  : LBRACKET (expression (COMMA expression)* COMMA?)? RBRACKET
+  | LBRACKET expression SEMI expression RBRACKET
+  ;
+
+// Not allowed directly in `if`/`while` conditions, where `{` opens the body.
+// Type arguments in expression position are only recognised when directly
+// followed by a call, e.g. `max<i32>(a, b)`.
+instantiation
+  : path typeArgs {_input.LA(1) == LPAREN}?
+  ;
+
+structLiteral
+  : path typeArgs? LBRACE fieldInitList? RBRACE
+  ;
+
+fieldInitList
+  : fieldInit (COMMA fieldInit)* COMMA?
+  ;
+
+fieldInit
+  : identifier COLON expression
+  ;
+
+blockLikeExpression
+  : ifExpression
+  | whileExpression
+  | loopExpression
+  | matchExpression
+  ;
+
+ifExpression
+  : IF expression block (ELSE (ifExpression | block))?
+  ;
+
+whileExpression
+  : WHILE expression block
+  ;
+
+loopExpression
+  : LOOP block
+  ;
+
+// Struct literals are not allowed directly in the scrutinee.
+matchExpression
+  : MATCH expression LBRACE matchArm* RBRACE
+  ;
+
+// The comma may be omitted after an arm whose body ends in a brace, and after
+// the last arm.
+matchArm
+  : pattern FATARROW (block | blockLikeExpression) COMMA?
+  | pattern FATARROW expression COMMA?
+  ;
+
+// A lone identifier binds; unit variants must be qualified (`Color::Red`).
+pattern
+  : UNDERSCORE
+  | MINUS? (IntegerLiteral | FloatLiteral)
+  | StringLiteral
+  | CharLiteral
+  | TRUE
+  | FALSE
+  | path (LPAREN (pattern (COMMA pattern)* COMMA?)? RPAREN)?
+  ;
+
 literal
   : IntegerLiteral
   | FloatLiteral
+  | StringLiteral
+  | CharLiteral
+  | TRUE
+  | FALSE
   ;
 
 identifier
@@ -7,10 +7,9 @@ use std::{
   sync::Arc,
 };
 
-use sable_ast::{
-  ast::Ast,
-  expression::Expression,
-  objects::function::FunctionParam,
+use sable_ast::ast::{
+  Arenas,
+  Ast,
 };
 use sable_common::{
   file::{
@@ -51,15 +50,10 @@ struct Args {
   input: Vec<String>,
 }
 
-struct ParseCtx<'ast, 'src> {
-  expr_arena: TypedArena<Expression<'ast, 'src>>,
-  param_arena: TypedArena<FunctionParam<'src>>,
-}
-
 fn parse_file<'src, 'ast, D>(
   source: Arc<Source<'src>, &'src TypedArena<Source<'src>>>,
   asts_arena: &'ast TypedArena<Ast<'ast, 'src>>,
-  ctx: &'ast ParseCtx<'ast, 'src>,
+  arenas: &'ast Arenas<'ast, 'src>,
   str_intern: &'ast StrInterner<'src>,
   writer: &mut D,
 ) -> Result<&'ast mut Ast<'ast, 'src>, ()>
@@ -67,7 +61,7 @@ where
   D: Sink<'src>,
   'src: 'ast,
 {
-  let ast = asts_arena.alloc(Ast::new(&ctx.expr_arena, &ctx.param_arena));
+  let ast = asts_arena.alloc(Ast::new(source.filename(), arenas));
 
   let lexer = Lexer::new(source.clone());
   let mut parser = Parser::new(lexer, ast, writer, str_intern);
@@ -83,14 +77,6 @@ where
   }
 }
 
-fn resolve_asts<'ast, 'src, 'resolve>(
-  asts: &'resolve mut [&'ast mut Ast<'ast, 'src>],
-  context: &'resolve mut Context<'resolve, 'src>,
-) -> Result<(), ()> {
-  let mut resolver = Resolver::new(asts, context);
-  resolver.resolve()
-}
-
 fn main() {
   let args = Args::parse();
 
@@ -107,7 +93,7 @@ fn main() {
   let package = Package::new(&item_arena);
 
   let mut sources = vec![];
-  let mut ctxs = vec![];
+  let mut arenas = vec![];
   let asts_arena = TypedArena::new();
 
   for filename in args.input {
@@ -121,19 +107,15 @@ fn main() {
 
     let src = manager.add_source(&source_code, &filename);
     sources.push(src);
-    let ctx = ParseCtx {
-      expr_arena: TypedArena::new(),
-      param_arena: TypedArena::new(),
-    };
-    ctxs.push(ctx);
+    arenas.push(Arenas::default());
   }
 
   let mut stdout = io::stdout();
   let mut writer = ReportWriter::new(manager.error_cache_mut(), &mut stdout);
 
   let mut asts = vec![];
-  for (source, ctx) in sources.iter().zip(ctxs.iter_mut()) {
-    match parse_file(source.clone(), &asts_arena, ctx, &str_intern, &mut writer) {
+  for (source, arenas) in sources.iter().zip(arenas.iter()) {
+    match parse_file(source.clone(), &asts_arena, arenas, &str_intern, &mut writer) {
       Ok(ast) => {
         asts.push(ast);
       }
@@ -143,11 +125,17 @@ fn main() {
     }
   }
 
-  resolve_asts(&mut asts, &mut context).unwrap_or_else(|_| {
+  let mut resolver = Resolver::new(&mut asts, &mut context, &mut writer);
+  resolver.resolve().unwrap_or_else(|_| {
     eprintln!("Failed to resolve ASTs.");
     std::process::exit(1);
   });
 
+  resolver.monomorphize().unwrap_or_else(|_| {
+    eprintln!("Failed to instantiate generics.");
+    std::process::exit(1);
+  });
+
   println!("{:#?}", asts);
   println!("{:#?}", package);
 }
\ No newline at end of file